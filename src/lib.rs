@@ -1,12 +1,183 @@
-use js_sys::{Array, Function, Object};
+use js_sys::{Array, Function, Object, WeakMap};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
-use web_sys::{Event, HtmlCanvasElement, HtmlElement};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    Blob, Element, Event, EventTarget, HtmlCanvasElement, HtmlElement, MediaQueryList,
+    OffscreenCanvas, PointerEvent, ResizeObserver, ResizeObserverEntry, SvgElement, SvgsvgElement,
+    WebGl2RenderingContext, Worker, XmlSerializer,
+};
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PowerPreference {
+    Default = "default",
+    HighPerformance = "high-performance",
+    LowPower = "low-power",
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Precision {
+    Highp = "highp",
+    Mediump = "mediump",
+    Lowp = "lowp",
+}
+
+/// The surface a `WebGLRenderer` draws into; an `OffscreenCanvas` lets the
+/// renderer live in a web worker.
+#[derive(Clone)]
+pub enum RendererCanvas {
+    Html(HtmlCanvasElement),
+    Offscreen(OffscreenCanvas),
+}
+
+impl From<HtmlCanvasElement> for RendererCanvas {
+    fn from(canvas: HtmlCanvasElement) -> Self {
+        Self::Html(canvas)
+    }
+}
+
+impl From<OffscreenCanvas> for RendererCanvas {
+    fn from(canvas: OffscreenCanvas) -> Self {
+        Self::Offscreen(canvas)
+    }
+}
+
+impl AsRef<JsValue> for RendererCanvas {
+    fn as_ref(&self) -> &JsValue {
+        match self {
+            Self::Html(canvas) => canvas,
+            Self::Offscreen(canvas) => canvas,
+        }
+    }
+}
 
 #[wasm_bindgen]
 #[wasm_bindgen(getter_with_clone)]
+#[derive(Clone)]
 pub struct WebGlRendererParameters {
-    pub canvas: Option<HtmlCanvasElement>,
+    #[wasm_bindgen(skip)]
+    pub canvas: Option<RendererCanvas>,
+    pub context: Option<WebGl2RenderingContext>,
+    pub precision: Precision,
+    pub alpha: bool,
+    #[wasm_bindgen(js_name = "premultipliedAlpha")]
+    pub premultiplied_alpha: bool,
     pub antialias: bool,
+    pub stencil: bool,
+    #[wasm_bindgen(js_name = "preserveDrawingBuffer")]
+    pub preserve_drawing_buffer: bool,
+    #[wasm_bindgen(js_name = "powerPreference")]
+    pub power_preference: PowerPreference,
+    #[wasm_bindgen(js_name = "failIfMajorPerformanceCaveat")]
+    pub fail_if_major_performance_caveat: bool,
+    pub depth: bool,
+    #[wasm_bindgen(js_name = "logarithmicDepthBuffer")]
+    pub logarithmic_depth_buffer: bool,
+    /// Applied with `setPixelRatio` after construction.
+    #[wasm_bindgen(skip)]
+    pub pixel_ratio: Option<f32>,
+}
+
+impl Default for WebGlRendererParameters {
+    fn default() -> Self {
+        Self {
+            canvas: None,
+            context: None,
+            precision: Precision::Highp,
+            alpha: false,
+            premultiplied_alpha: true,
+            antialias: false,
+            stencil: false,
+            preserve_drawing_buffer: false,
+            power_preference: PowerPreference::Default,
+            fail_if_major_performance_caveat: false,
+            depth: true,
+            logarithmic_depth_buffer: false,
+            pixel_ratio: None,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl WebGlRendererParameters {
+    #[wasm_bindgen(getter = canvas)]
+    pub fn canvas_value(&self) -> JsValue {
+        self.canvas
+            .as_ref()
+            .map_or(JsValue::UNDEFINED, |canvas| canvas.as_ref().clone())
+    }
+}
+
+impl WebGlRendererParameters {
+    pub fn builder() -> WebGlRendererParametersBuilder {
+        WebGlRendererParametersBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct WebGlRendererParametersBuilder {
+    params: WebGlRendererParameters,
+}
+
+impl WebGlRendererParametersBuilder {
+    pub fn canvas(mut self, canvas: impl Into<RendererCanvas>) -> Self {
+        self.params.canvas = Some(canvas.into());
+        self
+    }
+    pub fn context(mut self, context: WebGl2RenderingContext) -> Self {
+        self.params.context = Some(context);
+        self
+    }
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.params.precision = precision;
+        self
+    }
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.params.alpha = alpha;
+        self
+    }
+    pub fn premultiplied_alpha(mut self, premultiplied_alpha: bool) -> Self {
+        self.params.premultiplied_alpha = premultiplied_alpha;
+        self
+    }
+    pub fn antialias(mut self, antialias: bool) -> Self {
+        self.params.antialias = antialias;
+        self
+    }
+    pub fn stencil(mut self, stencil: bool) -> Self {
+        self.params.stencil = stencil;
+        self
+    }
+    pub fn preserve_drawing_buffer(mut self, preserve_drawing_buffer: bool) -> Self {
+        self.params.preserve_drawing_buffer = preserve_drawing_buffer;
+        self
+    }
+    pub fn power_preference(mut self, power_preference: PowerPreference) -> Self {
+        self.params.power_preference = power_preference;
+        self
+    }
+    pub fn fail_if_major_performance_caveat(mut self, fail: bool) -> Self {
+        self.params.fail_if_major_performance_caveat = fail;
+        self
+    }
+    pub fn depth(mut self, depth: bool) -> Self {
+        self.params.depth = depth;
+        self
+    }
+    pub fn logarithmic_depth_buffer(mut self, logarithmic_depth_buffer: bool) -> Self {
+        self.params.logarithmic_depth_buffer = logarithmic_depth_buffer;
+        self
+    }
+    pub fn pixel_ratio(mut self, pixel_ratio: f32) -> Self {
+        self.params.pixel_ratio = Some(pixel_ratio);
+        self
+    }
+    pub fn build(self) -> WebGlRendererParameters {
+        self.params
+    }
 }
 
 #[wasm_bindgen]
@@ -20,13 +191,1370 @@ impl MeshBasicMaterial {
         Self::constructor(JsValue::from(params).unchecked_ref())
     }
     pub fn set_color(&self, color: u32) {
-        set_material_color(&self, color);
+        set_material_color(self, color);
     }
 }
 
 impl WebGLRenderer {
     pub fn new(params: WebGlRendererParameters) -> Self {
-        Self::constructor(JsValue::from(params).unchecked_ref())
+        let pixel_ratio = params.pixel_ratio;
+        let renderer = Self::constructor(JsValue::from(params).unchecked_ref());
+        if let Some(pixel_ratio) = pixel_ratio {
+            renderer.set_pixel_ratio(pixel_ratio);
+        }
+        renderer
+    }
+    /// The canvas this renderer draws into, checked at runtime; `dom_element`
+    /// is typed as an `HtmlCanvasElement` even when it is an `OffscreenCanvas`.
+    pub fn canvas(&self) -> RendererCanvas {
+        match JsValue::from(self.dom_element()).dyn_into::<HtmlCanvasElement>() {
+            Ok(canvas) => RendererCanvas::Html(canvas),
+            Err(canvas) => RendererCanvas::Offscreen(canvas.unchecked_into()),
+        }
+    }
+    fn html_canvas(&self) -> Result<HtmlCanvasElement, JsValue> {
+        match self.canvas() {
+            RendererCanvas::Html(canvas) => Ok(canvas),
+            RendererCanvas::Offscreen(_) => Err(JsValue::from_str(
+                "renderer draws into an OffscreenCanvas, not a DOM canvas",
+            )),
+        }
+    }
+    pub fn size(&self) -> [f32; 2] {
+        let size = self.get_size(&Vector2::default());
+        [size.x(), size.y()]
+    }
+    pub fn drawing_buffer_size(&self) -> [f32; 2] {
+        let size = self.get_drawing_buffer_size(&Vector2::default());
+        [size.x(), size.y()]
+    }
+    pub fn viewport(&self) -> [f32; 4] {
+        let viewport = self.get_viewport(&Vector4::default());
+        [viewport.x(), viewport.y(), viewport.z(), viewport.w()]
+    }
+    /// Reads an `UnsignedByte` target; other types, including `HalfFloat`,
+    /// return an error, as does a region outside the target.
+    pub fn read_render_target_pixels(
+        &self,
+        target: &WebGLRenderTarget,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        buffer: &mut [u8],
+    ) -> Result<(), JsValue> {
+        target.check_readback(
+            [x, y, width, height],
+            TextureDataType::UnsignedByte,
+            buffer.len(),
+        )?;
+        self.read_render_target_pixels_unchecked(target, x, y, width, height, buffer);
+        Ok(())
+    }
+    /// Reads a `Float` target; other types return an error.
+    pub fn read_render_target_pixels_f32(
+        &self,
+        target: &WebGLRenderTarget,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        buffer: &mut [f32],
+    ) -> Result<(), JsValue> {
+        target.check_readback([x, y, width, height], TextureDataType::Float, buffer.len())?;
+        self.read_render_target_pixels_f32_unchecked(target, x, y, width, height, buffer);
+        Ok(())
+    }
+    pub fn is_context_lost(&self) -> bool {
+        self.get_context().is_context_lost()
+    }
+    pub fn renderer_info(&self) -> RendererInfo {
+        self.info().snapshot()
+    }
+    /// Runs `f(timestamp, delta)` every frame until the returned guard is dropped.
+    pub fn animation_loop(&self, f: impl FnMut(f64, f64) + 'static) -> AnimationLoop {
+        Renderer::animation_loop(self, f)
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MemoryInfo {
+    pub geometries: u32,
+    pub textures: u32,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RenderInfo {
+    pub calls: u32,
+    pub triangles: u32,
+    pub points: u32,
+    pub lines: u32,
+    pub frame: u32,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RendererInfo {
+    pub memory: MemoryInfo,
+    pub render: RenderInfo,
+}
+
+impl WebGLInfo {
+    pub fn snapshot(&self) -> RendererInfo {
+        let memory = self.memory();
+        let render = self.render();
+        RendererInfo {
+            memory: MemoryInfo {
+                geometries: memory.geometries(),
+                textures: memory.textures(),
+            },
+            render: RenderInfo {
+                calls: render.calls(),
+                triangles: render.triangles(),
+                points: render.points(),
+                lines: render.lines(),
+                frame: render.frame(),
+            },
+        }
+    }
+}
+
+/// Operations shared by `WebGLRenderer` and, with the `webgpu` feature,
+/// `WebGPURenderer`, so render code can be written once for both backends.
+pub trait Renderer: AsRef<JsValue> + Clone + 'static {
+    fn render(&self, scene: &Object3D, camera: &Camera);
+    fn set_size(&self, width: f32, height: f32, update_style: bool);
+    /// Unchecked; an `OffscreenCanvas` when the renderer was created on one.
+    fn dom_element(&self) -> HtmlCanvasElement;
+    fn set_animation_loop(&self, animate: Option<&Function>);
+
+    /// Runs `f(timestamp, delta)` every frame until the returned guard is dropped.
+    fn animation_loop(&self, f: impl FnMut(f64, f64) + 'static) -> AnimationLoop<Self> {
+        AnimationLoop::new(self.clone(), f)
+    }
+}
+
+impl Renderer for WebGLRenderer {
+    fn render(&self, scene: &Object3D, camera: &Camera) {
+        WebGLRenderer::render(self, scene, camera);
+    }
+    fn set_size(&self, width: f32, height: f32, update_style: bool) {
+        WebGLRenderer::set_size(self, width, height, update_style);
+    }
+    fn dom_element(&self) -> HtmlCanvasElement {
+        WebGLRenderer::dom_element(self)
+    }
+    fn set_animation_loop(&self, animate: Option<&Function>) {
+        WebGLRenderer::set_animation_loop(self, animate);
+    }
+}
+
+thread_local! {
+    /// The callback each renderer's most recent `AnimationLoop` installed.
+    static INSTALLED_LOOPS: WeakMap = WeakMap::new();
+}
+
+/// Owns the closure passed to `setAnimationLoop`.
+///
+/// Pausing or dropping a guard only clears the renderer's loop while that
+/// loop is still this guard's, so `self.frame = renderer.animation_loop(g)`
+/// keeps the new loop running when the old guard is dropped. Loops set
+/// through [`Renderer::set_animation_loop`] directly are not tracked.
+pub struct AnimationLoop<R: Renderer = WebGLRenderer> {
+    renderer: R,
+    closure: Closure<dyn FnMut(f64)>,
+    last_time: Rc<Cell<Option<f64>>>,
+    paused: bool,
+}
+
+impl<R: Renderer> AnimationLoop<R> {
+    pub fn new(renderer: R, mut f: impl FnMut(f64, f64) + 'static) -> Self {
+        let last_time = Rc::new(Cell::new(None));
+        let closure = Closure::<dyn FnMut(f64)>::new({
+            let last_time = last_time.clone();
+            move |time: f64| {
+                let delta = last_time.replace(Some(time)).map_or(0., |last| time - last);
+                f(time, delta);
+            }
+        });
+        let animation_loop = Self {
+            renderer,
+            closure,
+            last_time,
+            paused: false,
+        };
+        animation_loop.install();
+        animation_loop
+    }
+    fn install(&self) {
+        let callback: &JsValue = self.closure.as_ref();
+        INSTALLED_LOOPS.with(|loops| loops.set(self.renderer.as_ref().unchecked_ref(), callback));
+        self.renderer
+            .set_animation_loop(Some(callback.unchecked_ref()));
+    }
+    fn uninstall(&self) {
+        let key: &Object = self.renderer.as_ref().unchecked_ref();
+        let installed = INSTALLED_LOOPS.with(|loops| {
+            let installed = loops.get(key) == *self.closure.as_ref();
+            if installed {
+                loops.delete(key);
+            }
+            installed
+        });
+        if installed {
+            self.renderer.set_animation_loop(None);
+        }
+    }
+    pub fn pause(&mut self) {
+        if !self.paused {
+            self.uninstall();
+            self.paused = true;
+        }
+    }
+    /// Restarts the loop; the first frame after resuming reports a delta of zero.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.last_time.set(None);
+            self.install();
+            self.paused = false;
+        }
+    }
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+    pub fn renderer(&self) -> &R {
+        &self.renderer
+    }
+}
+
+impl<R: Renderer> Drop for AnimationLoop<R> {
+    fn drop(&mut self) {
+        if !self.paused {
+            self.uninstall();
+        }
+    }
+}
+
+#[cfg(feature = "webgpu")]
+#[derive(Clone)]
+pub struct WebGpuRendererParameters {
+    pub canvas: Option<HtmlCanvasElement>,
+    pub alpha: bool,
+    pub depth: bool,
+    pub stencil: bool,
+    pub antialias: bool,
+    pub samples: u32,
+    pub logarithmic_depth_buffer: bool,
+    pub force_webgl: bool,
+    pub power_preference: PowerPreference,
+}
+
+#[cfg(feature = "webgpu")]
+impl Default for WebGpuRendererParameters {
+    fn default() -> Self {
+        Self {
+            canvas: None,
+            alpha: true,
+            depth: true,
+            stencil: false,
+            antialias: false,
+            samples: 0,
+            logarithmic_depth_buffer: false,
+            force_webgl: false,
+            power_preference: PowerPreference::Default,
+        }
+    }
+}
+
+#[cfg(feature = "webgpu")]
+impl WebGpuRendererParameters {
+    fn to_object(&self) -> Object {
+        let options = Object::new();
+        if let Some(canvas) = &self.canvas {
+            set_property(&options, "canvas", canvas);
+        }
+        set_property(&options, "alpha", &self.alpha.into());
+        set_property(&options, "depth", &self.depth.into());
+        set_property(&options, "stencil", &self.stencil.into());
+        set_property(&options, "antialias", &self.antialias.into());
+        set_property(&options, "samples", &self.samples.into());
+        set_property(
+            &options,
+            "logarithmicDepthBuffer",
+            &self.logarithmic_depth_buffer.into(),
+        );
+        set_property(&options, "forceWebGL", &self.force_webgl.into());
+        set_property(&options, "powerPreference", &self.power_preference.into());
+        options
+    }
+}
+
+#[cfg(feature = "webgpu")]
+impl WebGPURenderer {
+    pub fn new(params: &WebGpuRendererParameters) -> Self {
+        Self::constructor(&params.to_object())
+    }
+    pub async fn init(&self) -> Result<(), JsValue> {
+        JsFuture::from(self.init_promise()).await.map(|_| ())
+    }
+    pub async fn render_async(&self, scene: &Object3D, camera: &Camera) -> Result<(), JsValue> {
+        JsFuture::from(self.render_async_promise(scene, camera))
+            .await
+            .map(|_| ())
+    }
+}
+
+#[cfg(feature = "webgpu")]
+impl Renderer for WebGPURenderer {
+    fn render(&self, scene: &Object3D, camera: &Camera) {
+        WebGPURenderer::render(self, scene, camera);
+    }
+    fn set_size(&self, width: f32, height: f32, update_style: bool) {
+        WebGPURenderer::set_size(self, width, height, update_style);
+    }
+    fn dom_element(&self) -> HtmlCanvasElement {
+        WebGPURenderer::dom_element(self)
+    }
+    fn set_animation_loop(&self, animate: Option<&Function>) {
+        WebGPURenderer::set_animation_loop(self, animate);
+    }
+}
+
+/// Sets an own property on a plain object. Option bags for three.js are built
+/// this way because several constructors read them with `Object.assign`, which
+/// ignores the prototype getters of a `#[wasm_bindgen]` struct.
+fn set_property(target: &Object, key: &str, value: &JsValue) {
+    js_sys::Reflect::set(target, &JsValue::from_str(key), value).unwrap_throw();
+}
+
+#[derive(Clone)]
+pub struct RenderTargetOptions {
+    pub samples: u32,
+    pub depth_buffer: bool,
+    pub stencil_buffer: bool,
+    pub depth_texture: Option<DepthTexture>,
+    pub texture_type: TextureDataType,
+    pub format: PixelFormat,
+}
+
+impl Default for RenderTargetOptions {
+    fn default() -> Self {
+        Self {
+            samples: 0,
+            depth_buffer: true,
+            stencil_buffer: false,
+            depth_texture: None,
+            texture_type: TextureDataType::UnsignedByte,
+            format: PixelFormat::RGBA,
+        }
+    }
+}
+
+impl RenderTargetOptions {
+    fn to_object(&self) -> Object {
+        let options = Object::new();
+        set_property(&options, "samples", &self.samples.into());
+        set_property(&options, "depthBuffer", &self.depth_buffer.into());
+        set_property(&options, "stencilBuffer", &self.stencil_buffer.into());
+        if let Some(depth_texture) = &self.depth_texture {
+            set_property(&options, "depthTexture", depth_texture);
+        }
+        set_property(&options, "type", &(self.texture_type as u32).into());
+        set_property(&options, "format", &(self.format as u32).into());
+        options
+    }
+}
+
+impl WebGLRenderTarget {
+    pub fn new(width: u32, height: u32, options: &RenderTargetOptions) -> Self {
+        Self::constructor(width, height, &options.to_object())
+    }
+    /// Fails unless a `width` x `height` read at `x`, `y` lies inside this
+    /// target and fits `len` values of `element`, since WebGL and three.js
+    /// otherwise skip the read and leave the buffer untouched.
+    fn check_readback(
+        &self,
+        [x, y, width, height]: [u32; 4],
+        element: TextureDataType,
+        len: usize,
+    ) -> Result<(), JsValue> {
+        let texture = self.texture();
+        let texture_type = texture.texture_type_value();
+        if TextureDataType::from_u32(texture_type) != Some(element) {
+            return Err(JsValue::from_str(&format!(
+                "readback into {element:?} values needs a target of that type, not type {texture_type}"
+            )));
+        }
+        if x as u64 + width as u64 > self.width() as u64
+            || y as u64 + height as u64 > self.height() as u64
+        {
+            return Err(JsValue::from_str(&format!(
+                "readback region {width}x{height} at ({x}, {y}) exceeds the {}x{} target",
+                self.width(),
+                self.height()
+            )));
+        }
+        let format = texture.format_value();
+        let channels = PixelFormat::from_u32(format)
+            .map(PixelFormat::channels)
+            .ok_or_else(|| JsValue::from_str(&format!("unsupported readback format {format}")))?;
+        let required = width as usize * height as usize * channels;
+        if len < required {
+            return Err(JsValue::from_str(&format!(
+                "readback buffer holds {len} values, {required} required"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Texture {
+    /// `None` for types this crate does not model, such as `UnsignedInt5999Type`.
+    pub fn texture_type(&self) -> Option<TextureDataType> {
+        TextureDataType::from_u32(self.texture_type_value())
+    }
+    /// `None` for formats this crate does not model, such as compressed formats.
+    pub fn format(&self) -> Option<PixelFormat> {
+        PixelFormat::from_u32(self.format_value())
+    }
+}
+
+impl TextureDataType {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        Some(match value {
+            1009 => Self::UnsignedByte,
+            1010 => Self::Byte,
+            1011 => Self::Short,
+            1012 => Self::UnsignedShort,
+            1013 => Self::Int,
+            1014 => Self::UnsignedInt,
+            1015 => Self::Float,
+            1016 => Self::HalfFloat,
+            1017 => Self::UnsignedShort4444,
+            1018 => Self::UnsignedShort5551,
+            1020 => Self::UnsignedInt248,
+            _ => return None,
+        })
+    }
+}
+
+impl PixelFormat {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        Some(match value {
+            1021 => Self::Alpha,
+            1022 => Self::RGB,
+            1023 => Self::RGBA,
+            1026 => Self::Depth,
+            1027 => Self::DepthStencil,
+            1028 => Self::Red,
+            1029 => Self::RedInteger,
+            1030 => Self::RG,
+            1031 => Self::RGInteger,
+            1033 => Self::RGBAInteger,
+            _ => return None,
+        })
+    }
+    pub fn channels(self) -> usize {
+        match self {
+            Self::Alpha | Self::Depth | Self::DepthStencil | Self::Red | Self::RedInteger => 1,
+            Self::RG | Self::RGInteger => 2,
+            Self::RGB => 3,
+            Self::RGBA | Self::RGBAInteger => 4,
+        }
+    }
+}
+
+type RenderCallback =
+    dyn FnMut(WebGLRenderer, Object3D, Camera, BufferGeometry, Material, Option<GeometryGroup>);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RenderHookKind {
+    BeforeRender,
+    AfterRender,
+}
+
+impl RenderHookKind {
+    fn property(self) -> &'static str {
+        match self {
+            Self::BeforeRender => "onBeforeRender",
+            Self::AfterRender => "onAfterRender",
+        }
+    }
+}
+
+/// A Rust closure installed as `onBeforeRender` or `onAfterRender`; dropping
+/// it restores the default no-op unless another hook has replaced it since.
+/// three.js only invokes these hooks for objects that are drawn, such as
+/// meshes, lines, points and sprites, passing the root given to `render` and
+/// the geometry group being drawn for multi-material meshes.
+pub struct RenderHook {
+    object: Object3D,
+    kind: RenderHookKind,
+    callback: Closure<RenderCallback>,
+}
+
+impl RenderHook {
+    pub fn new(
+        object: &Object3D,
+        kind: RenderHookKind,
+        mut f: impl FnMut(
+            &WebGLRenderer,
+            &Object3D,
+            &Camera,
+            &BufferGeometry,
+            &Material,
+            Option<&GeometryGroup>,
+        ) + 'static,
+    ) -> Self {
+        let callback = Closure::<RenderCallback>::new(
+            move |renderer, root, camera, geometry, material, group: Option<GeometryGroup>| {
+                f(
+                    &renderer,
+                    &root,
+                    &camera,
+                    &geometry,
+                    &material,
+                    group.as_ref(),
+                )
+            },
+        );
+        let function = callback.as_ref().unchecked_ref();
+        match kind {
+            RenderHookKind::BeforeRender => object.set_on_before_render(function),
+            RenderHookKind::AfterRender => object.set_on_after_render(function),
+        }
+        Self {
+            object: object.clone(),
+            kind,
+            callback,
+        }
+    }
+    pub fn kind(&self) -> RenderHookKind {
+        self.kind
+    }
+}
+
+impl Drop for RenderHook {
+    fn drop(&mut self) {
+        reset_render_hook(
+            &self.object,
+            self.kind.property(),
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// Typed `clone`/`copy` for scene graph objects. three.js clones through
+/// `new this.constructor()`, so the result has the same class as `self`.
+pub trait CloneObject3D: JsCast + AsRef<Object3D> {
+    fn clone_object(&self, recursive: bool) -> Self {
+        Object3D::clone_object(self.as_ref(), recursive).unchecked_into()
+    }
+    /// Clones recursively, also cloning geometries and materials instead of
+    /// sharing them. Resources shared inside the hierarchy stay shared between
+    /// the cloned objects.
+    fn clone_deep(&self) -> Self {
+        deep_clone_object(self.as_ref()).unchecked_into()
+    }
+    fn copy_from(&self, source: &Self, recursive: bool) {
+        Object3D::copy_object(self.as_ref(), source.as_ref(), recursive);
+    }
+}
+
+impl CloneObject3D for Object3D {}
+impl CloneObject3D for Scene {}
+impl CloneObject3D for Group {}
+impl CloneObject3D for Mesh {}
+impl CloneObject3D for Camera {}
+impl CloneObject3D for PerspectiveCamera {}
+impl CloneObject3D for OrthographicCamera {}
+impl CloneObject3D for Light {}
+impl CloneObject3D for AmbientLight {}
+impl CloneObject3D for DirectionalLight {}
+impl CloneObject3D for PointLight {}
+impl CloneObject3D for SpotLight {}
+
+/// Typed `clone`/`copy` for materials.
+pub trait CloneMaterial: JsCast + AsRef<Material> {
+    fn clone_material(&self) -> Self {
+        Material::clone_material(self.as_ref()).unchecked_into()
+    }
+    fn copy_from(&self, source: &Self) {
+        Material::copy_material(self.as_ref(), source.as_ref());
+    }
+}
+
+impl CloneMaterial for Material {}
+impl CloneMaterial for MeshBasicMaterial {}
+impl CloneMaterial for MeshStandardMaterial {}
+
+/// Typed `clone`/`copy` for geometries.
+pub trait CloneGeometry: JsCast + AsRef<BufferGeometry> {
+    fn clone_geometry(&self) -> Self {
+        BufferGeometry::clone_geometry(self.as_ref()).unchecked_into()
+    }
+    fn copy_from(&self, source: &Self) {
+        BufferGeometry::copy_geometry(self.as_ref(), source.as_ref());
+    }
+}
+
+impl CloneGeometry for BufferGeometry {}
+impl CloneGeometry for BoxGeometry {}
+impl CloneGeometry for CircleGeometry {}
+impl CloneGeometry for CylinderGeometry {}
+impl CloneGeometry for SphereGeometry {}
+impl CloneGeometry for PlaneGeometry {}
+impl CloneGeometry for ShapeGeometry {}
+
+/// Keeps a renderer and camera sized to an element, observing the renderer's
+/// canvas itself when no container is given. Stops observing when dropped.
+///
+/// When observing the canvas directly, its size must come from CSS (for
+/// example `width: 100%; height: 100%`). Otherwise the drawing buffer size,
+/// which includes the pixel ratio, becomes its layout size and every resize
+/// grows it further. Needs a renderer on a DOM canvas; one created on an
+/// `OffscreenCanvas` is an error, and a worker is resized with
+/// [`WorkerMessage::Resize`] instead.
+///
+/// With `max_pixel_ratio` set, each resize also applies `devicePixelRatio`
+/// capped at that value. Pass `None` when the ratio is owned elsewhere, such
+/// as by a [`PixelRatioWatcher`], so the two do not overwrite each other.
+pub struct ViewportSync {
+    observer: ResizeObserver,
+    _callback: Closure<dyn FnMut(Array)>,
+}
+
+impl ViewportSync {
+    pub fn new(
+        renderer: &WebGLRenderer,
+        camera: &PerspectiveCamera,
+        container: Option<&Element>,
+        max_pixel_ratio: Option<f32>,
+    ) -> Result<Self, JsValue> {
+        let canvas = renderer.html_canvas()?;
+        let target: Element = container.cloned().unwrap_or_else(|| canvas.into());
+        // A canvas inside an observed container gets its CSS size from us; an
+        // observed canvas already has one and only needs its drawing buffer set.
+        let update_style = container.is_some();
+        let callback = Closure::<dyn FnMut(Array)>::new({
+            let renderer = renderer.clone();
+            let camera = camera.clone();
+            move |entries: Array| {
+                let Some(entry) = entries.iter().last() else {
+                    return;
+                };
+                let rect = entry.unchecked_into::<ResizeObserverEntry>().content_rect();
+                let (width, height) = (rect.width() as f32, rect.height() as f32);
+                if width <= 0. || height <= 0. {
+                    return;
+                }
+                if let Some((ratio, max)) = device_pixel_ratio().zip(max_pixel_ratio) {
+                    renderer.set_pixel_ratio(ratio.min(max));
+                }
+                renderer.set_size(width, height, update_style);
+                camera.set_aspect(width / height);
+                camera.update_projection_matrix();
+            }
+        });
+        let observer = ResizeObserver::new(callback.as_ref().unchecked_ref())?;
+        observer.observe(&target);
+        Ok(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for ViewportSync {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+#[cfg(feature = "postprocessing")]
+impl OutlinePass {
+    pub fn selected_objects(&self) -> Vec<Object3D> {
+        self.selected_objects_array()
+            .iter()
+            .map(JsCast::unchecked_into)
+            .collect()
+    }
+    pub fn set_selected_objects(&self, objects: &[Object3D]) {
+        self.set_selected_objects_array(&objects.iter().collect());
+    }
+}
+
+#[cfg(feature = "postprocessing")]
+impl ShaderPass {
+    pub fn fxaa() -> Self {
+        FXAA_SHADER.with(Self::new)
+    }
+    /// Sets the `resolution` uniform used by FXAA to the inverse drawing buffer
+    /// size, failing for shaders without a `Vector2` resolution uniform.
+    pub fn set_resolution(&self, width: f32, height: f32, pixel_ratio: f32) -> Result<(), JsValue> {
+        let uniform = js_sys::Reflect::get(&self.uniforms(), &"resolution".into())?;
+        if !uniform.is_object() {
+            return Err(JsValue::from_str("shader has no resolution uniform"));
+        }
+        let resolution = js_sys::Reflect::get(&uniform, &"value".into())?
+            .dyn_into::<Vector2>()
+            .map_err(|_| JsValue::from_str("resolution uniform is not a Vector2"))?;
+        resolution.set(1. / (width * pixel_ratio), 1. / (height * pixel_ratio));
+        Ok(())
+    }
+}
+
+impl CSS3DRenderer {
+    /// Creates a renderer that draws into an existing element instead of its own `div`.
+    pub fn new(element: &HtmlElement) -> Self {
+        Self::constructor(&css_renderer_parameters(element))
+    }
+}
+
+impl CSS2DRenderer {
+    /// Creates a renderer that draws into an existing element instead of its own `div`.
+    pub fn new(element: &HtmlElement) -> Self {
+        Self::constructor(&css_renderer_parameters(element))
+    }
+}
+
+fn css_renderer_parameters(element: &HtmlElement) -> Object {
+    let parameters = Object::new();
+    set_property(&parameters, "element", element);
+    parameters
+}
+
+/// A region of the canvas as fractions of its size, measured from the bottom left
+/// like `setViewport`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ViewportRect {
+    pub left: f32,
+    pub bottom: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ViewportRect {
+    pub const FULL: Self = Self::new(0., 0., 1., 1.);
+
+    pub const fn new(left: f32, bottom: f32, width: f32, height: f32) -> Self {
+        Self {
+            left,
+            bottom,
+            width,
+            height,
+        }
+    }
+    /// One quarter of a 2x2 grid, with `column` and `row` counted from the top left.
+    pub const fn quad(column: u8, row: u8) -> Self {
+        Self::new(column as f32 * 0.5, 0.5 - row as f32 * 0.5, 0.5, 0.5)
+    }
+}
+
+/// Renders several scene/camera pairs into separate regions of one canvas.
+#[derive(Clone, Default)]
+pub struct Viewports {
+    views: Vec<(Object3D, Camera, ViewportRect)>,
+}
+
+impl Viewports {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn push(&mut self, scene: &Object3D, camera: &Camera, rect: ViewportRect) {
+        self.views.push((scene.clone(), camera.clone(), rect));
+    }
+    pub fn clear(&mut self) {
+        self.views.clear();
+    }
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+    pub fn render(&self, renderer: &WebGLRenderer) {
+        let [width, height] = renderer.size();
+        let auto_clear = renderer.auto_clear();
+        renderer.set_auto_clear(false);
+        renderer.set_scissor_test(false);
+        renderer.clear(true, true, true);
+        renderer.set_scissor_test(true);
+        for (scene, camera, rect) in &self.views {
+            let x = rect.left * width;
+            let y = rect.bottom * height;
+            let w = rect.width * width;
+            let h = rect.height * height;
+            renderer.set_viewport(x, y, w, h);
+            renderer.set_scissor(x, y, w, h);
+            renderer.render(scene, camera);
+        }
+        renderer.set_scissor_test(false);
+        renderer.set_viewport(0., 0., width, height);
+        renderer.set_auto_clear(auto_clear);
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageFormat {
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct ScreenshotOptions {
+    pub format: ImageFormat,
+    /// Encoder quality between 0 and 1 for lossy formats.
+    pub quality: Option<f64>,
+    /// Output size in pixels; the renderer's current drawing buffer is used when unset.
+    pub size: Option<(u32, u32)>,
+}
+
+/// A camera's projection before a sized capture changed its aspect.
+enum SavedProjection {
+    Perspective(PerspectiveCamera, f32),
+    Orthographic(OrthographicCamera, f32, f32),
+}
+
+impl SavedProjection {
+    fn save(camera: &Camera) -> Result<Self, JsValue> {
+        if let Some(camera) = camera.dyn_ref::<PerspectiveCamera>() {
+            Ok(Self::Perspective(camera.clone(), camera.aspect()))
+        } else if let Some(camera) = camera.dyn_ref::<OrthographicCamera>() {
+            Ok(Self::Orthographic(
+                camera.clone(),
+                camera.left(),
+                camera.right(),
+            ))
+        } else {
+            Err(JsValue::from_str(
+                "a sized screenshot needs a perspective or orthographic camera",
+            ))
+        }
+    }
+    /// Orthographic cameras keep their vertical extent and horizontal center.
+    fn set_aspect(&self, aspect: f32) {
+        match self {
+            Self::Perspective(camera, _) => {
+                camera.set_aspect(aspect);
+                camera.update_projection_matrix();
+            }
+            Self::Orthographic(camera, left, right) => {
+                let center = (left + right) / 2.;
+                let half_width = (camera.top() - camera.bottom()) / 2. * aspect;
+                camera.set_left(center - half_width);
+                camera.set_right(center + half_width);
+                camera.update_projection_matrix();
+            }
+        }
+    }
+    fn restore(&self) {
+        match self {
+            Self::Perspective(camera, aspect) => {
+                camera.set_aspect(*aspect);
+                camera.update_projection_matrix();
+            }
+            Self::Orthographic(camera, left, right) => {
+                camera.set_left(*left);
+                camera.set_right(*right);
+                camera.update_projection_matrix();
+            }
+        }
+    }
+}
+
+/// Renders one frame and encodes the canvas into a `Blob`.
+///
+/// The canvas is read in the same task as the render, so this works without
+/// `preserveDrawingBuffer`. When `size` is set the renderer and a perspective
+/// or orthographic camera are resized for the capture and restored
+/// afterwards; other cameras and a zero width or height are errors, as is
+/// a renderer on an `OffscreenCanvas`, whose `convertToBlob` has no
+/// equivalent here.
+pub async fn capture_screenshot(
+    renderer: &WebGLRenderer,
+    scene: &Object3D,
+    camera: &Camera,
+    options: &ScreenshotOptions,
+) -> Result<Blob, JsValue> {
+    if matches!(options.size, Some((0, _) | (_, 0))) {
+        return Err(JsValue::from_str("screenshot size must not be zero"));
+    }
+    let canvas = renderer.html_canvas()?;
+    let restore = match options.size {
+        Some((width, height)) => {
+            let projection = SavedProjection::save(camera)?;
+            let previous = (renderer.size(), renderer.get_pixel_ratio());
+            renderer.set_pixel_ratio(1.);
+            renderer.set_size(width as f32, height as f32, false);
+            projection.set_aspect(width as f32 / height as f32);
+            Some((previous, projection))
+        }
+        None => None,
+    };
+    renderer.render(scene, camera);
+    let blob = canvas_to_blob(&canvas, options);
+    if let Some((([width, height], pixel_ratio), projection)) = restore {
+        renderer.set_pixel_ratio(pixel_ratio);
+        renderer.set_size(width, height, false);
+        projection.restore();
+        renderer.render(scene, camera);
+    }
+    Ok(JsFuture::from(blob).await?.unchecked_into())
+}
+
+/// Like [`capture_screenshot`], returning the encoded image bytes.
+pub async fn capture_screenshot_bytes(
+    renderer: &WebGLRenderer,
+    scene: &Object3D,
+    camera: &Camera,
+    options: &ScreenshotOptions,
+) -> Result<Vec<u8>, JsValue> {
+    let blob = capture_screenshot(renderer, scene, camera, options).await?;
+    let buffer = JsFuture::from(blob.array_buffer()).await?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+fn canvas_to_blob(canvas: &HtmlCanvasElement, options: &ScreenshotOptions) -> js_sys::Promise {
+    js_sys::Promise::new(&mut |resolve, reject| {
+        let on_blob = Closure::once_into_js({
+            let reject = reject.clone();
+            move |blob: JsValue| {
+                if blob.is_null() {
+                    let error = js_sys::Error::new("canvas could not be encoded");
+                    let _ = reject.call1(&JsValue::NULL, &error);
+                } else {
+                    let _ = resolve.call1(&JsValue::NULL, &blob);
+                }
+            }
+        });
+        let quality = options.quality.map_or(JsValue::UNDEFINED, JsValue::from);
+        if let Err(error) = canvas.to_blob_with_type_and_encoder_options(
+            on_blob.unchecked_ref(),
+            options.format.mime_type(),
+            &quality,
+        ) {
+            let _ = reject.call1(&JsValue::NULL, &error);
+        }
+    })
+}
+
+/// Listens for `webglcontextlost` and `webglcontextrestored` on a renderer's
+/// canvas until dropped. Both canvas kinds dispatch these events, so this
+/// also works for a renderer on an `OffscreenCanvas` in a worker.
+///
+/// three.js rebuilds its GL state on restore; `on_restored` runs afterwards and
+/// is the place to flag textures and render targets for re-upload.
+pub struct ContextLossHandler {
+    canvas: EventTarget,
+    lost: Rc<Cell<bool>>,
+    on_lost: Closure<dyn FnMut(Event)>,
+    on_restored: Closure<dyn FnMut(Event)>,
+}
+
+impl ContextLossHandler {
+    pub fn new(
+        renderer: &WebGLRenderer,
+        mut on_lost: impl FnMut() + 'static,
+        mut on_restored: impl FnMut() + 'static,
+    ) -> Result<Self, JsValue> {
+        let canvas: EventTarget = renderer.dom_element().into();
+        let lost = Rc::new(Cell::new(false));
+        let on_lost = Closure::<dyn FnMut(Event)>::new({
+            let lost = lost.clone();
+            move |event: Event| {
+                // Without this the browser never attempts to restore the context.
+                event.prevent_default();
+                lost.set(true);
+                on_lost();
+            }
+        });
+        let on_restored = Closure::<dyn FnMut(Event)>::new({
+            let lost = lost.clone();
+            move |_: Event| {
+                lost.set(false);
+                on_restored();
+            }
+        });
+        canvas.add_event_listener_with_callback(
+            "webglcontextlost",
+            on_lost.as_ref().unchecked_ref(),
+        )?;
+        canvas.add_event_listener_with_callback(
+            "webglcontextrestored",
+            on_restored.as_ref().unchecked_ref(),
+        )?;
+        Ok(Self {
+            canvas,
+            lost,
+            on_lost,
+            on_restored,
+        })
+    }
+    pub fn is_lost(&self) -> bool {
+        self.lost.get()
+    }
+}
+
+impl Drop for ContextLossHandler {
+    fn drop(&mut self) {
+        let _ = self.canvas.remove_event_listener_with_callback(
+            "webglcontextlost",
+            self.on_lost.as_ref().unchecked_ref(),
+        );
+        let _ = self.canvas.remove_event_listener_with_callback(
+            "webglcontextrestored",
+            self.on_restored.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+impl SVGRenderer {
+    /// Serializes the last rendered frame as SVG markup.
+    pub fn to_svg_string(&self) -> Result<String, JsValue> {
+        XmlSerializer::new()?.serialize_to_string(&self.dom_element())
+    }
+}
+
+fn device_pixel_ratio() -> Option<f32> {
+    web_sys::window().map(|window| window.device_pixel_ratio() as f32)
+}
+
+/// Applies `window.devicePixelRatio`, capped at a maximum, to a renderer and
+/// re-applies it whenever the ratio changes, such as when the window moves to
+/// another monitor. Stops watching when dropped.
+pub struct PixelRatioWatcher {
+    state: Rc<PixelRatioState>,
+}
+
+struct PixelRatioState {
+    renderer: WebGLRenderer,
+    max_pixel_ratio: f32,
+    on_change: RefCell<Box<dyn FnMut(f32)>>,
+    query: RefCell<Option<MediaQueryList>>,
+    callback: RefCell<Option<Closure<dyn FnMut()>>>,
+}
+
+impl PixelRatioWatcher {
+    pub fn new(
+        renderer: &WebGLRenderer,
+        max_pixel_ratio: f32,
+        on_change: impl FnMut(f32) + 'static,
+    ) -> Result<Self, JsValue> {
+        let state = Rc::new(PixelRatioState {
+            renderer: renderer.clone(),
+            max_pixel_ratio,
+            on_change: RefCell::new(Box::new(on_change)),
+            query: RefCell::new(None),
+            callback: RefCell::new(None),
+        });
+        let weak = Rc::downgrade(&state);
+        state
+            .callback
+            .replace(Some(Closure::<dyn FnMut()>::new(move || {
+                if let Some(state) = weak.upgrade() {
+                    let _ = state.watch();
+                }
+            })));
+        state.watch()?;
+        Ok(Self { state })
+    }
+    pub fn pixel_ratio(&self) -> f32 {
+        self.state.renderer.get_pixel_ratio()
+    }
+}
+
+impl PixelRatioState {
+    // A resolution query only matches one ratio, so it is replaced after each change.
+    fn watch(&self) -> Result<(), JsValue> {
+        let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+        let ratio = window.device_pixel_ratio();
+        let capped = (ratio as f32).min(self.max_pixel_ratio);
+        self.renderer.set_pixel_ratio(capped);
+        (self.on_change.borrow_mut())(capped);
+        self.unwatch();
+        let query = window
+            .match_media(&format!("(resolution: {ratio}dppx)"))?
+            .ok_or_else(|| JsValue::from_str("matchMedia returned null"))?;
+        if let Some(callback) = self.callback.borrow().as_ref() {
+            query.add_event_listener_with_callback("change", callback.as_ref().unchecked_ref())?;
+        }
+        self.query.replace(Some(query));
+        Ok(())
+    }
+    fn unwatch(&self) {
+        if let (Some(query), Some(callback)) = (self.query.take(), self.callback.borrow().as_ref())
+        {
+            let _ = query
+                .remove_event_listener_with_callback("change", callback.as_ref().unchecked_ref());
+        }
+    }
+}
+
+impl Drop for PixelRatioWatcher {
+    fn drop(&mut self) {
+        self.state.unwatch();
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PointerEventKind {
+    Down,
+    Move,
+    Up,
+    Cancel,
+}
+
+impl PointerEventKind {
+    pub const ALL: [Self; 4] = [Self::Down, Self::Move, Self::Up, Self::Cancel];
+
+    pub fn event_type(self) -> &'static str {
+        match self {
+            Self::Down => "pointerdown",
+            Self::Move => "pointermove",
+            Self::Up => "pointerup",
+            Self::Cancel => "pointercancel",
+        }
+    }
+    pub fn from_event_type(event_type: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.event_type() == event_type)
+    }
+}
+
+/// A pointer event with coordinates relative to the canvas, as sent to a worker.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ForwardedPointer {
+    pub kind: PointerEventKind,
+    pub pointer_id: i32,
+    pub x: f32,
+    pub y: f32,
+    pub button: i16,
+    pub buttons: u16,
+    pub ctrl_key: bool,
+    pub shift_key: bool,
+    pub alt_key: bool,
+    pub meta_key: bool,
+}
+
+impl ForwardedPointer {
+    pub fn from_event(kind: PointerEventKind, event: &PointerEvent) -> Self {
+        Self {
+            kind,
+            pointer_id: event.pointer_id(),
+            x: event.offset_x() as f32,
+            y: event.offset_y() as f32,
+            button: event.button(),
+            buttons: event.buttons(),
+            ctrl_key: event.ctrl_key(),
+            shift_key: event.shift_key(),
+            alt_key: event.alt_key(),
+            meta_key: event.meta_key(),
+        }
+    }
+}
+
+/// Messages posted from the main thread to a worker that owns the renderer.
+///
+/// In the worker, apply `Resize` with `setPixelRatio` and `setSize(width,
+/// height, false)`; an `OffscreenCanvas` has no style to update. Its size is
+/// mirrored onto the placeholder canvas, which therefore needs a CSS size of
+/// its own, as with [`ViewportSync`].
+#[derive(Clone)]
+pub enum WorkerMessage {
+    Canvas(OffscreenCanvas),
+    Resize {
+        width: f32,
+        height: f32,
+        pixel_ratio: f32,
+    },
+    Pointer(ForwardedPointer),
+}
+
+impl WorkerMessage {
+    pub fn to_js(&self) -> Object {
+        let message = Object::new();
+        match self {
+            Self::Canvas(canvas) => {
+                set_property(&message, "type", &"canvas".into());
+                set_property(&message, "canvas", canvas);
+            }
+            Self::Resize {
+                width,
+                height,
+                pixel_ratio,
+            } => {
+                set_property(&message, "type", &"resize".into());
+                set_property(&message, "width", &(*width).into());
+                set_property(&message, "height", &(*height).into());
+                set_property(&message, "pixelRatio", &(*pixel_ratio).into());
+            }
+            Self::Pointer(pointer) => {
+                set_property(&message, "type", &pointer.kind.event_type().into());
+                set_property(&message, "pointerId", &pointer.pointer_id.into());
+                set_property(&message, "x", &pointer.x.into());
+                set_property(&message, "y", &pointer.y.into());
+                set_property(&message, "button", &pointer.button.into());
+                set_property(&message, "buttons", &pointer.buttons.into());
+                set_property(&message, "ctrlKey", &pointer.ctrl_key.into());
+                set_property(&message, "shiftKey", &pointer.shift_key.into());
+                set_property(&message, "altKey", &pointer.alt_key.into());
+                set_property(&message, "metaKey", &pointer.meta_key.into());
+            }
+        }
+        message
+    }
+    /// Decodes a message received by the worker, returning `None` for anything
+    /// this protocol did not produce.
+    pub fn from_js(message: &JsValue) -> Option<Self> {
+        let get = |key: &str| js_sys::Reflect::get(message, &JsValue::from_str(key)).ok();
+        let number = |key: &str| get(key).and_then(|value| value.as_f64());
+        let flag = |key: &str| get(key).and_then(|value| value.as_bool()).unwrap_or(false);
+        let message_type = get("type")?.as_string()?;
+        match message_type.as_str() {
+            "canvas" => get("canvas")?.dyn_into().ok().map(Self::Canvas),
+            "resize" => Some(Self::Resize {
+                width: number("width")? as f32,
+                height: number("height")? as f32,
+                pixel_ratio: number("pixelRatio")? as f32,
+            }),
+            event_type => Some(Self::Pointer(ForwardedPointer {
+                kind: PointerEventKind::from_event_type(event_type)?,
+                pointer_id: number("pointerId")? as i32,
+                x: number("x")? as f32,
+                y: number("y")? as f32,
+                button: number("button")? as i16,
+                buttons: number("buttons")? as u16,
+                ctrl_key: flag("ctrlKey"),
+                shift_key: flag("shiftKey"),
+                alt_key: flag("altKey"),
+                meta_key: flag("metaKey"),
+            })),
+        }
+    }
+    pub fn post(&self, worker: &Worker) -> Result<(), JsValue> {
+        match self {
+            Self::Canvas(canvas) => {
+                worker.post_message_with_transfer(&self.to_js(), &Array::of1(canvas))
+            }
+            _ => worker.post_message(&self.to_js()),
+        }
+    }
+}
+
+/// Hands a canvas over to a worker and keeps forwarding its size and pointer
+/// events there until dropped. The canvas must be sized by CSS, since the
+/// worker's drawing buffer size would otherwise feed back into its layout.
+pub struct CanvasForwarder {
+    canvas: HtmlCanvasElement,
+    observer: ResizeObserver,
+    _on_resize: Closure<dyn FnMut(Array)>,
+    on_pointer: Vec<Closure<dyn FnMut(PointerEvent)>>,
+}
+
+impl CanvasForwarder {
+    pub fn new(canvas: &HtmlCanvasElement, worker: &Worker) -> Result<Self, JsValue> {
+        let offscreen = canvas.transfer_control_to_offscreen()?;
+        WorkerMessage::Canvas(offscreen).post(worker)?;
+        let on_resize = Closure::<dyn FnMut(Array)>::new({
+            let worker = worker.clone();
+            move |entries: Array| {
+                let Some(entry) = entries.iter().last() else {
+                    return;
+                };
+                let rect = entry.unchecked_into::<ResizeObserverEntry>().content_rect();
+                let message = WorkerMessage::Resize {
+                    width: rect.width() as f32,
+                    height: rect.height() as f32,
+                    pixel_ratio: device_pixel_ratio().unwrap_or(1.),
+                };
+                let _ = message.post(&worker);
+            }
+        });
+        let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref())?;
+        observer.observe(canvas);
+        let mut on_pointer = Vec::with_capacity(PointerEventKind::ALL.len());
+        for kind in PointerEventKind::ALL {
+            let worker = worker.clone();
+            let callback = Closure::<dyn FnMut(PointerEvent)>::new(move |event: PointerEvent| {
+                let message = WorkerMessage::Pointer(ForwardedPointer::from_event(kind, &event));
+                let _ = message.post(&worker);
+            });
+            canvas.add_event_listener_with_callback(
+                kind.event_type(),
+                callback.as_ref().unchecked_ref(),
+            )?;
+            on_pointer.push(callback);
+        }
+        Ok(Self {
+            canvas: canvas.clone(),
+            observer,
+            _on_resize: on_resize,
+            on_pointer,
+        })
+    }
+}
+
+impl Drop for CanvasForwarder {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+        for (kind, callback) in PointerEventKind::ALL.into_iter().zip(&self.on_pointer) {
+            let _ = self.canvas.remove_event_listener_with_callback(
+                kind.event_type(),
+                callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+/// Calls `f` on every `sessionstart` or `sessionend` of a renderer's XR
+/// manager until dropped.
+#[cfg(feature = "xr")]
+pub struct XrSessionListener {
+    xr: WebXRManager,
+    on_start: Closure<dyn FnMut()>,
+    on_end: Closure<dyn FnMut()>,
+}
+
+#[cfg(feature = "xr")]
+impl XrSessionListener {
+    pub fn new(
+        renderer: &WebGLRenderer,
+        on_start: impl FnMut() + 'static,
+        on_end: impl FnMut() + 'static,
+    ) -> Self {
+        let xr = renderer.xr();
+        let on_start = Closure::<dyn FnMut()>::new(on_start);
+        let on_end = Closure::<dyn FnMut()>::new(on_end);
+        xr.add_event_listener("sessionstart", on_start.as_ref().unchecked_ref());
+        xr.add_event_listener("sessionend", on_end.as_ref().unchecked_ref());
+        Self {
+            xr,
+            on_start,
+            on_end,
+        }
+    }
+}
+
+#[cfg(feature = "xr")]
+impl Drop for XrSessionListener {
+    fn drop(&mut self) {
+        self.xr
+            .remove_event_listner("sessionstart", self.on_start.as_ref().unchecked_ref());
+        self.xr
+            .remove_event_listner("sessionend", self.on_end.as_ref().unchecked_ref());
     }
 }
 
@@ -40,6 +1568,7 @@ impl Object3D {
     pub fn set_quaternion(&self, x: f32, y: f32, z: f32, w: f32) {
         batch_update_quaternion(self, x, y, z, w);
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn set_transform(
         &self,
         x: f32,
@@ -58,6 +1587,177 @@ impl Object3D {
     pub fn add_position(&self, x: f32, y: f32, z: f32) {
         batch_add_position(self, x, y, z);
     }
+    pub fn set_rotation(&self, x: f32, y: f32, z: f32) {
+        batch_update_rotation(self, x, y, z);
+    }
+    pub fn get_objects_by_property(&self, name: &str, value: &JsValue) -> Vec<Object3D> {
+        self.get_objects_by_property_array(name, value)
+            .iter()
+            .map(JsCast::unchecked_into)
+            .collect()
+    }
+    /// Stores `value` under `userData[key]` as plain JS objects and arrays.
+    #[cfg(feature = "serde")]
+    pub fn set_user_data<T: serde::Serialize + ?Sized>(
+        &self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), serde_wasm_bindgen::Error> {
+        let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+        set_user_data(self, key, &value.serialize(&serializer)?);
+        Ok(())
+    }
+    /// Reads `userData[key]`, failing if it is missing or does not match `T`.
+    #[cfg(feature = "serde")]
+    pub fn get_user_data<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+    ) -> Result<T, serde_wasm_bindgen::Error> {
+        serde_wasm_bindgen::from_value(get_user_data(self, key))
+    }
+    pub fn look_at_array(&self, [x, y, z]: [f32; 3]) {
+        self.look_at(x, y, z);
+    }
+    pub fn local_to_world_array(&self, point: [f32; 3]) -> [f32; 3] {
+        let mut out = point;
+        local_to_world_in_place(self, &mut out);
+        out
+    }
+    pub fn world_to_local_array(&self, point: [f32; 3]) -> [f32; 3] {
+        let mut out = point;
+        world_to_local_in_place(self, &mut out);
+        out
+    }
+    pub fn world_position_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_world_position(self, &mut out);
+        out
+    }
+    pub fn world_quaternion_array(&self) -> [f32; 4] {
+        let mut out = [0.; 4];
+        copy_world_quaternion(self, &mut out);
+        out
+    }
+    pub fn world_scale_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_world_scale(self, &mut out);
+        out
+    }
+    pub fn world_direction_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_world_direction(self, &mut out);
+        out
+    }
+    /// Rotates around a normalized axis in local space.
+    pub fn rotate_on_axis_array(&self, [x, y, z]: [f32; 3], angle: f32) {
+        rotate_object_on_axis(self, x, y, z, angle);
+    }
+    /// Moves along a normalized axis in local space.
+    pub fn translate_on_axis_array(&self, [x, y, z]: [f32; 3], distance: f32) {
+        translate_object_on_axis(self, x, y, z, distance);
+    }
+    /// Applies a matrix given in three.js' column-major element order.
+    pub fn apply_matrix4_array(&self, elements: &[f32; 16]) {
+        apply_matrix_elements(self, elements);
+    }
+    /// Writes the local matrix directly, for objects with `matrixAutoUpdate`
+    /// turned off. `position`, `quaternion` and `scale` are left untouched.
+    pub fn compose_matrix(&self, position: [f32; 3], quaternion: [f32; 4], scale: [f32; 3]) {
+        let [px, py, pz] = position;
+        let [qx, qy, qz, qw] = quaternion;
+        let [sx, sy, sz] = scale;
+        compose_object_matrix(self, px, py, pz, qx, qy, qz, qw, sx, sy, sz);
+    }
+    /// Like [`Object3D::compose_matrix`], from column-major matrix elements.
+    pub fn set_matrix_array(&self, elements: &[f32; 16]) {
+        set_object_matrix(self, elements);
+    }
+    pub fn on_before_render(
+        &self,
+        f: impl FnMut(
+            &WebGLRenderer,
+            &Object3D,
+            &Camera,
+            &BufferGeometry,
+            &Material,
+            Option<&GeometryGroup>,
+        ) + 'static,
+    ) -> RenderHook {
+        RenderHook::new(self, RenderHookKind::BeforeRender, f)
+    }
+    pub fn on_after_render(
+        &self,
+        f: impl FnMut(
+            &WebGLRenderer,
+            &Object3D,
+            &Camera,
+            &BufferGeometry,
+            &Material,
+            Option<&GeometryGroup>,
+        ) + 'static,
+    ) -> RenderHook {
+        RenderHook::new(self, RenderHookKind::AfterRender, f)
+    }
+    /// Calls `f` on this object and all of its descendants.
+    pub fn traverse(&self, mut f: impl FnMut(&Object3D)) {
+        self.traverse_with(&mut |object| f(&object));
+    }
+    /// Like [`Object3D::traverse`], skipping invisible objects and their descendants.
+    pub fn traverse_visible(&self, mut f: impl FnMut(&Object3D)) {
+        self.traverse_visible_with(&mut |object| f(&object));
+    }
+    /// Calls `f` on every ancestor of this object, nearest first.
+    pub fn traverse_ancestors(&self, mut f: impl FnMut(&Object3D)) {
+        self.traverse_ancestors_with(&mut |object| f(&object));
+    }
+    /// Iterates over a snapshot of the children, so the graph may be edited meanwhile.
+    pub fn iter_children(&self) -> impl ExactSizeIterator<Item = Object3D> + use<> {
+        let children = Array::from(&self.children());
+        (0..children.length()).map(move |index| children.get(index).unchecked_into())
+    }
+    pub fn position_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_position(self, &mut out);
+        out
+    }
+    pub fn rotation_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_rotation(self, &mut out);
+        out
+    }
+    pub fn quaternion_array(&self) -> [f32; 4] {
+        let mut out = [0.; 4];
+        copy_quaternion(self, &mut out);
+        out
+    }
+    pub fn scale_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_scale(self, &mut out);
+        out
+    }
+    pub fn up_array(&self) -> [f32; 3] {
+        let mut out = [0.; 3];
+        copy_up(self, &mut out);
+        out
+    }
+    /// The local matrix in three.js' column-major element order.
+    pub fn matrix_array(&self) -> [f32; 16] {
+        let mut out = [0.; 16];
+        copy_matrix(self, &mut out);
+        out
+    }
+    /// The world matrix in three.js' column-major element order.
+    pub fn matrix_world_array(&self) -> [f32; 16] {
+        let mut out = [0.; 16];
+        copy_matrix_world(self, &mut out);
+        out
+    }
+}
+
+impl LightShadow {
+    pub fn set_map_size(&self, width: f32, height: f32) {
+        self.map_size().set(width, height);
+    }
 }
 
 impl Raycaster {
@@ -77,6 +1777,85 @@ impl Raycaster {
     }
 }
 
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShadowMapType {
+    Basic = 0,
+    PCF = 1,
+    PCFSoft = 2,
+    VSM = 3,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToneMapping {
+    None = 0,
+    Linear = 1,
+    Reinhard = 2,
+    Cineon = 3,
+    ACESFilmic = 4,
+    Custom = 5,
+    AgX = 6,
+    Neutral = 7,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorSpace {
+    None = "",
+    SRGB = "srgb",
+    LinearSRGB = "srgb-linear",
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextureDataType {
+    UnsignedByte = 1009,
+    Byte = 1010,
+    Short = 1011,
+    UnsignedShort = 1012,
+    Int = 1013,
+    UnsignedInt = 1014,
+    Float = 1015,
+    HalfFloat = 1016,
+    UnsignedShort4444 = 1017,
+    UnsignedShort5551 = 1018,
+    UnsignedInt248 = 1020,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PixelFormat {
+    Alpha = 1021,
+    RGB = 1022,
+    RGBA = 1023,
+    Depth = 1026,
+    DepthStencil = 1027,
+    Red = 1028,
+    RedInteger = 1029,
+    RG = 1030,
+    RGInteger = 1031,
+    RGBAInteger = 1033,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SvgQuality {
+    High = "high",
+    Low = "low",
+}
+
+#[cfg(feature = "xr")]
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum XrReferenceSpaceType {
+    Viewer = "viewer",
+    Local = "local",
+    LocalFloor = "local-floor",
+    BoundedFloor = "bounded-floor",
+    Unbounded = "unbounded",
+}
+
 #[derive(Default)]
 pub enum LoopMode {
     Once = 2200,
@@ -97,13 +1876,154 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn render(this: &WebGLRenderer, scene: &Object3D, camera: &Camera);
     #[wasm_bindgen(method, js_name = "setAnimationLoop")]
-    pub fn set_animation_loop(this: &WebGLRenderer, animate: &Function);
+    pub fn set_animation_loop(this: &WebGLRenderer, animate: Option<&Function>);
     #[wasm_bindgen(method, js_name = "setSize")]
     pub fn set_size(this: &WebGLRenderer, width: f32, height: f32, update_style: bool);
+    /// Unchecked; see [`WebGLRenderer::canvas`] for a renderer that may be
+    /// on an `OffscreenCanvas`.
     #[wasm_bindgen(method, getter, js_name = "domElement")]
     pub fn dom_element(this: &WebGLRenderer) -> HtmlCanvasElement;
     #[wasm_bindgen(method, js_name = "setClearColor")]
     pub fn set_clear_color(this: &WebGLRenderer, color: u32);
+    #[wasm_bindgen(method, js_name = "setPixelRatio")]
+    pub fn set_pixel_ratio(this: &WebGLRenderer, ratio: f32);
+    #[wasm_bindgen(method, js_name = "getPixelRatio")]
+    pub fn get_pixel_ratio(this: &WebGLRenderer) -> f32;
+    #[wasm_bindgen(method, js_name = "getDrawingBufferSize")]
+    pub fn get_drawing_buffer_size(this: &WebGLRenderer, target: &Vector2) -> Vector2;
+    #[wasm_bindgen(method, js_name = "getSize")]
+    pub fn get_size(this: &WebGLRenderer, target: &Vector2) -> Vector2;
+    #[wasm_bindgen(method, js_name = "setViewport")]
+    pub fn set_viewport(this: &WebGLRenderer, x: f32, y: f32, width: f32, height: f32);
+    #[wasm_bindgen(method, js_name = "getViewport")]
+    pub fn get_viewport(this: &WebGLRenderer, target: &Vector4) -> Vector4;
+    #[wasm_bindgen(method, js_name = "setScissor")]
+    pub fn set_scissor(this: &WebGLRenderer, x: f32, y: f32, width: f32, height: f32);
+    #[wasm_bindgen(method, js_name = "getScissor")]
+    pub fn get_scissor(this: &WebGLRenderer, target: &Vector4) -> Vector4;
+    #[wasm_bindgen(method, js_name = "setScissorTest")]
+    pub fn set_scissor_test(this: &WebGLRenderer, enabled: bool);
+    #[wasm_bindgen(method, js_name = "getScissorTest")]
+    pub fn get_scissor_test(this: &WebGLRenderer) -> bool;
+    #[wasm_bindgen(method, getter, js_name = "autoClear")]
+    pub fn auto_clear(this: &WebGLRenderer) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "autoClear")]
+    pub fn set_auto_clear(this: &WebGLRenderer, auto_clear: bool);
+    #[wasm_bindgen(method)]
+    pub fn clear(this: &WebGLRenderer, color: bool, depth: bool, stencil: bool);
+    #[wasm_bindgen(method, js_name = "clearColor")]
+    pub fn clear_color(this: &WebGLRenderer);
+    #[wasm_bindgen(method, js_name = "clearDepth")]
+    pub fn clear_depth(this: &WebGLRenderer);
+    #[wasm_bindgen(method, js_name = "clearStencil")]
+    pub fn clear_stencil(this: &WebGLRenderer);
+    #[wasm_bindgen(method, getter)]
+    pub fn info(this: &WebGLRenderer) -> WebGLInfo;
+    #[wasm_bindgen(method, js_name = "getContext")]
+    pub fn get_context(this: &WebGLRenderer) -> WebGl2RenderingContext;
+    #[cfg(feature = "xr")]
+    #[wasm_bindgen(method, getter)]
+    pub fn xr(this: &WebGLRenderer) -> WebXRManager;
+    #[wasm_bindgen(method, js_name = "forceContextLoss")]
+    pub fn force_context_loss(this: &WebGLRenderer);
+    #[wasm_bindgen(method, js_name = "forceContextRestore")]
+    pub fn force_context_restore(this: &WebGLRenderer);
+    #[wasm_bindgen(method, getter, js_name = "shadowMap")]
+    pub fn shadow_map(this: &WebGLRenderer) -> WebGLShadowMap;
+    #[wasm_bindgen(method, js_name = "setRenderTarget")]
+    pub fn set_render_target(this: &WebGLRenderer, target: Option<&WebGLRenderTarget>);
+    #[wasm_bindgen(method, js_name = "getRenderTarget")]
+    pub fn get_render_target(this: &WebGLRenderer) -> Option<WebGLRenderTarget>;
+    #[wasm_bindgen(method, js_name = "readRenderTargetPixels")]
+    pub fn read_render_target_pixels_unchecked(
+        this: &WebGLRenderer,
+        target: &WebGLRenderTarget,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        buffer: &mut [u8],
+    );
+    #[wasm_bindgen(method, js_name = "readRenderTargetPixels")]
+    pub fn read_render_target_pixels_f32_unchecked(
+        this: &WebGLRenderer,
+        target: &WebGLRenderTarget,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        buffer: &mut [f32],
+    );
+    #[wasm_bindgen(method, getter, js_name = "toneMapping")]
+    pub fn tone_mapping(this: &WebGLRenderer) -> ToneMapping;
+    #[wasm_bindgen(method, setter, js_name = "toneMapping")]
+    pub fn set_tone_mapping(this: &WebGLRenderer, tone_mapping: ToneMapping);
+    #[wasm_bindgen(method, getter, js_name = "toneMappingExposure")]
+    pub fn tone_mapping_exposure(this: &WebGLRenderer) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "toneMappingExposure")]
+    pub fn set_tone_mapping_exposure(this: &WebGLRenderer, exposure: f32);
+    #[wasm_bindgen(method, getter, js_name = "outputColorSpace")]
+    pub fn output_color_space(this: &WebGLRenderer) -> ColorSpace;
+    #[wasm_bindgen(method, setter, js_name = "outputColorSpace")]
+    pub fn set_output_color_space(this: &WebGLRenderer, color_space: ColorSpace);
+
+    pub type WebGLShadowMap;
+    #[wasm_bindgen(method, getter)]
+    pub fn enabled(this: &WebGLShadowMap) -> bool;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_enabled(this: &WebGLShadowMap, enabled: bool);
+    #[wasm_bindgen(method, getter = type)]
+    pub fn shadow_type(this: &WebGLShadowMap) -> ShadowMapType;
+    #[wasm_bindgen(method, setter = type)]
+    pub fn set_shadow_type(this: &WebGLShadowMap, shadow_type: ShadowMapType);
+    #[wasm_bindgen(method, getter, js_name = "autoUpdate")]
+    pub fn auto_update(this: &WebGLShadowMap) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "autoUpdate")]
+    pub fn set_auto_update(this: &WebGLShadowMap, auto_update: bool);
+    #[wasm_bindgen(method, getter, js_name = "needsUpdate")]
+    pub fn needs_update(this: &WebGLShadowMap) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "needsUpdate")]
+    pub fn set_needs_update(this: &WebGLShadowMap, needs_update: bool);
+
+    pub type WebGLInfo;
+    #[wasm_bindgen(method, getter)]
+    pub fn memory(this: &WebGLInfo) -> WebGLInfoMemory;
+    #[wasm_bindgen(method, getter)]
+    pub fn render(this: &WebGLInfo) -> WebGLInfoRender;
+    #[wasm_bindgen(method, getter, js_name = "autoReset")]
+    pub fn auto_reset(this: &WebGLInfo) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "autoReset")]
+    pub fn set_auto_reset(this: &WebGLInfo, auto_reset: bool);
+    #[wasm_bindgen(method)]
+    pub fn reset(this: &WebGLInfo);
+
+    pub type WebGLInfoMemory;
+    #[wasm_bindgen(method, getter)]
+    pub fn geometries(this: &WebGLInfoMemory) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn textures(this: &WebGLInfoMemory) -> u32;
+
+    pub type WebGLInfoRender;
+    #[wasm_bindgen(method, getter)]
+    pub fn calls(this: &WebGLInfoRender) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn triangles(this: &WebGLInfoRender) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn points(this: &WebGLInfoRender) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn lines(this: &WebGLInfoRender) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn frame(this: &WebGLInfoRender) -> u32;
+
+    pub type ColorManagement;
+    #[wasm_bindgen(static_method_of = ColorManagement, getter)]
+    pub fn enabled() -> bool;
+    #[wasm_bindgen(static_method_of = ColorManagement, setter)]
+    pub fn set_enabled(enabled: bool);
+    #[wasm_bindgen(static_method_of = ColorManagement, getter, js_name = "workingColorSpace")]
+    pub fn working_color_space() -> ColorSpace;
+    #[wasm_bindgen(static_method_of = ColorManagement, setter, js_name = "workingColorSpace")]
+    pub fn set_working_color_space(color_space: ColorSpace);
 
     #[derive(Clone)]
     #[wasm_bindgen(extends = EventDispatcher)]
@@ -112,12 +2032,29 @@ extern "C" {
     pub fn id(this: &Object3D) -> u32;
     #[wasm_bindgen(method, getter)]
     pub fn uuid(this: &Object3D) -> String;
+    #[wasm_bindgen(method, getter)]
+    pub fn name(this: &Object3D) -> String;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_name(this: &Object3D, name: &str);
+    #[wasm_bindgen(method, js_name = "getObjectByName")]
+    pub fn get_object_by_name(this: &Object3D, name: &str) -> Option<Object3D>;
+    #[wasm_bindgen(method, js_name = "getObjectById")]
+    pub fn get_object_by_id(this: &Object3D, id: u32) -> Option<Object3D>;
+    #[wasm_bindgen(method, js_name = "getObjectByProperty")]
+    pub fn get_object_by_property(this: &Object3D, name: &str, value: &JsValue)
+    -> Option<Object3D>;
+    #[wasm_bindgen(method, js_name = "getObjectsByProperty")]
+    pub fn get_objects_by_property_array(this: &Object3D, name: &str, value: &JsValue) -> Array;
     #[wasm_bindgen(method, variadic)]
     pub fn add(this: &Object3D, children: Vec<Object3D>);
     #[wasm_bindgen(method, js_name = "add")]
     pub fn add_1(this: &Object3D, child: &Object3D);
     #[wasm_bindgen(method, js_name = "removeFromParent")]
     pub fn remove_from_parent(this: &Object3D);
+    #[wasm_bindgen(method, js_name = "clone")]
+    pub fn clone_object(this: &Object3D, recursive: bool) -> Object3D;
+    #[wasm_bindgen(method, js_name = "copy")]
+    pub fn copy_object(this: &Object3D, source: &Object3D, recursive: bool);
     #[wasm_bindgen(method, variadic)]
     pub fn remove(this: &Object3D, children: Array);
     #[wasm_bindgen(method)]
@@ -127,11 +2064,91 @@ extern "C" {
     #[wasm_bindgen(method, getter)]
     pub fn children(this: &Object3D) -> Array;
     #[wasm_bindgen(method, getter)]
+    pub fn parent(this: &Object3D) -> Option<Object3D>;
+    #[wasm_bindgen(method, js_name = "traverse")]
+    pub fn traverse_with(this: &Object3D, callback: &mut dyn FnMut(Object3D));
+    #[wasm_bindgen(method, js_name = "traverseVisible")]
+    pub fn traverse_visible_with(this: &Object3D, callback: &mut dyn FnMut(Object3D));
+    #[wasm_bindgen(method, js_name = "traverseAncestors")]
+    pub fn traverse_ancestors_with(this: &Object3D, callback: &mut dyn FnMut(Object3D));
+    #[wasm_bindgen(method, getter)]
     pub fn position(this: &Object3D) -> Vector3;
+    #[wasm_bindgen(method, getter)]
+    pub fn rotation(this: &Object3D) -> Euler;
+    #[wasm_bindgen(method, getter)]
+    pub fn quaternion(this: &Object3D) -> Quaternion;
+    #[wasm_bindgen(method, getter)]
+    pub fn scale(this: &Object3D) -> Vector3;
+    #[wasm_bindgen(method, getter)]
+    pub fn up(this: &Object3D) -> Vector3;
+    #[wasm_bindgen(method, getter)]
+    pub fn matrix(this: &Object3D) -> Matrix4;
+    #[wasm_bindgen(method, getter, js_name = "matrixWorld")]
+    pub fn matrix_world(this: &Object3D) -> Matrix4;
     #[wasm_bindgen(method, js_name = "updateMatrix")]
     pub fn update_matrix(this: &Object3D);
+    #[wasm_bindgen(method, js_name = "lookAt")]
+    pub fn look_at(this: &Object3D, x: f32, y: f32, z: f32);
+    #[wasm_bindgen(method, js_name = "lookAt")]
+    pub fn look_at_vector(this: &Object3D, target: &Vector3);
+    #[wasm_bindgen(method, js_name = "localToWorld")]
+    pub fn local_to_world(this: &Object3D, vector: &Vector3) -> Vector3;
+    #[wasm_bindgen(method, js_name = "worldToLocal")]
+    pub fn world_to_local(this: &Object3D, vector: &Vector3) -> Vector3;
+    #[wasm_bindgen(method, js_name = "getWorldPosition")]
+    pub fn get_world_position(this: &Object3D, target: &Vector3) -> Vector3;
+    #[wasm_bindgen(method, js_name = "getWorldQuaternion")]
+    pub fn get_world_quaternion(this: &Object3D, target: &Quaternion) -> Quaternion;
+    #[wasm_bindgen(method, js_name = "getWorldScale")]
+    pub fn get_world_scale(this: &Object3D, target: &Vector3) -> Vector3;
+    #[wasm_bindgen(method, js_name = "getWorldDirection")]
+    pub fn get_world_direction(this: &Object3D, target: &Vector3) -> Vector3;
+    #[wasm_bindgen(method, js_name = "rotateOnAxis")]
+    pub fn rotate_on_axis(this: &Object3D, axis: &Vector3, angle: f32) -> Object3D;
+    #[wasm_bindgen(method, js_name = "rotateOnWorldAxis")]
+    pub fn rotate_on_world_axis(this: &Object3D, axis: &Vector3, angle: f32) -> Object3D;
+    #[wasm_bindgen(method, js_name = "translateOnAxis")]
+    pub fn translate_on_axis(this: &Object3D, axis: &Vector3, distance: f32) -> Object3D;
+    #[wasm_bindgen(method, js_name = "applyMatrix4")]
+    pub fn apply_matrix4(this: &Object3D, matrix: &Matrix4);
     #[wasm_bindgen(method, js_name = "updateMatrixWorld")]
     pub fn update_matrix_world(this: &Object3D, force: bool);
+    #[wasm_bindgen(method, getter)]
+    pub fn visible(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_visible(this: &Object3D, visible: bool);
+    #[wasm_bindgen(method, getter, js_name = "renderOrder")]
+    pub fn render_order(this: &Object3D) -> i32;
+    #[wasm_bindgen(method, setter, js_name = "renderOrder")]
+    pub fn set_render_order(this: &Object3D, render_order: i32);
+    #[wasm_bindgen(method, getter, js_name = "frustumCulled")]
+    pub fn frustum_culled(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "frustumCulled")]
+    pub fn set_frustum_culled(this: &Object3D, frustum_culled: bool);
+    #[wasm_bindgen(method, getter, js_name = "matrixAutoUpdate")]
+    pub fn matrix_auto_update(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "matrixAutoUpdate")]
+    pub fn set_matrix_auto_update(this: &Object3D, matrix_auto_update: bool);
+    #[wasm_bindgen(method, getter, js_name = "matrixWorldAutoUpdate")]
+    pub fn matrix_world_auto_update(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "matrixWorldAutoUpdate")]
+    pub fn set_matrix_world_auto_update(this: &Object3D, matrix_world_auto_update: bool);
+    #[wasm_bindgen(method, getter, js_name = "matrixWorldNeedsUpdate")]
+    pub fn matrix_world_needs_update(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "matrixWorldNeedsUpdate")]
+    pub fn set_matrix_world_needs_update(this: &Object3D, needs_update: bool);
+    #[wasm_bindgen(method, setter, js_name = "onBeforeRender")]
+    pub fn set_on_before_render(this: &Object3D, callback: &Function);
+    #[wasm_bindgen(method, setter, js_name = "onAfterRender")]
+    pub fn set_on_after_render(this: &Object3D, callback: &Function);
+    #[wasm_bindgen(method, getter, js_name = "castShadow")]
+    pub fn cast_shadow(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "castShadow")]
+    pub fn set_cast_shadow(this: &Object3D, cast_shadow: bool);
+    #[wasm_bindgen(method, getter, js_name = "receiveShadow")]
+    pub fn receive_shadow(this: &Object3D) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "receiveShadow")]
+    pub fn set_receive_shadow(this: &Object3D, receive_shadow: bool);
 
     #[derive(Clone)]
     #[wasm_bindgen(extends = Object3D)]
@@ -139,6 +2156,7 @@ extern "C" {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Scene;
 
+    #[derive(Clone)]
     #[wasm_bindgen(extends = Object3D)]
     pub type Group;
     #[wasm_bindgen(constructor)]
@@ -149,7 +2167,7 @@ extern "C" {
     pub type Camera;
 
     #[derive(Clone)]
-    #[wasm_bindgen(extends = Camera)]
+    #[wasm_bindgen(extends = Camera, extends = Object3D)]
     pub type PerspectiveCamera;
     #[wasm_bindgen(constructor)]
     pub fn new(fov: f32, aspect: f32, near: f32, far: f32) -> PerspectiveCamera;
@@ -160,37 +2178,160 @@ extern "C" {
     #[wasm_bindgen(method, setter)]
     pub fn set_aspect(this: &PerspectiveCamera, aspect: f32);
     #[wasm_bindgen(method, getter)]
-    pub fn far(this: &PerspectiveCamera) -> f32;
+    pub fn far(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_far(this: &PerspectiveCamera, far: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn film_gauge(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_film_gauge(this: &PerspectiveCamera, film_guage: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn film_offset(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_film_offset(this: &PerspectiveCamera, film_offset: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn focus(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_focus(this: &PerspectiveCamera, focus: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn fov(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_fov(this: &PerspectiveCamera, fov: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn near(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_near(this: &PerspectiveCamera, near: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn view(this: &PerspectiveCamera) -> js_sys::Object;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_view(this: &PerspectiveCamera, view: &js_sys::Object);
+    #[wasm_bindgen(method, getter)]
+    pub fn zoom(this: &PerspectiveCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_zoom(this: &PerspectiveCamera, zoom: f32);
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Camera, extends = Object3D)]
+    pub type OrthographicCamera;
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        left: f32,
+        right: f32,
+        top: f32,
+        bottom: f32,
+        near: f32,
+        far: f32,
+    ) -> OrthographicCamera;
+    #[wasm_bindgen(method, js_name = "updateProjectionMatrix")]
+    pub fn update_projection_matrix(this: &OrthographicCamera);
+    #[wasm_bindgen(method, getter)]
+    pub fn left(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_left(this: &OrthographicCamera, left: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn right(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_right(this: &OrthographicCamera, right: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn top(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_top(this: &OrthographicCamera, top: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn bottom(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_bottom(this: &OrthographicCamera, bottom: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn near(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_near(this: &OrthographicCamera, near: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn far(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_far(this: &OrthographicCamera, far: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn zoom(this: &OrthographicCamera) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_zoom(this: &OrthographicCamera, zoom: f32);
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Object3D)]
+    pub type Light;
+    #[wasm_bindgen(method, getter)]
+    pub fn intensity(this: &Light) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_intensity(this: &Light, intensity: f32);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &Light);
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Light, extends = Object3D)]
+    pub type AmbientLight;
+    #[wasm_bindgen(constructor)]
+    pub fn new(color: u32, intensity: f32) -> AmbientLight;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Light, extends = Object3D)]
+    pub type DirectionalLight;
+    #[wasm_bindgen(constructor)]
+    pub fn new(color: u32, intensity: f32) -> DirectionalLight;
+    #[wasm_bindgen(method, getter)]
+    pub fn target(this: &DirectionalLight) -> Object3D;
     #[wasm_bindgen(method, setter)]
-    pub fn set_far(this: &PerspectiveCamera, far: f32);
+    pub fn set_target(this: &DirectionalLight, target: &Object3D);
     #[wasm_bindgen(method, getter)]
-    pub fn film_gauge(this: &PerspectiveCamera) -> f32;
-    #[wasm_bindgen(method, setter)]
-    pub fn set_film_gauge(this: &PerspectiveCamera, film_guage: f32);
+    pub fn shadow(this: &DirectionalLight) -> LightShadow;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Light, extends = Object3D)]
+    pub type PointLight;
+    #[wasm_bindgen(constructor)]
+    pub fn new(color: u32, intensity: f32, distance: f32, decay: f32) -> PointLight;
     #[wasm_bindgen(method, getter)]
-    pub fn film_offset(this: &PerspectiveCamera) -> f32;
-    #[wasm_bindgen(method, setter)]
-    pub fn set_film_offset(this: &PerspectiveCamera, film_offset: f32);
+    pub fn shadow(this: &PointLight) -> LightShadow;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Light, extends = Object3D)]
+    pub type SpotLight;
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        color: u32,
+        intensity: f32,
+        distance: f32,
+        angle: f32,
+        penumbra: f32,
+        decay: f32,
+    ) -> SpotLight;
     #[wasm_bindgen(method, getter)]
-    pub fn focus(this: &PerspectiveCamera) -> f32;
+    pub fn target(this: &SpotLight) -> Object3D;
     #[wasm_bindgen(method, setter)]
-    pub fn set_focus(this: &PerspectiveCamera, focus: f32);
+    pub fn set_target(this: &SpotLight, target: &Object3D);
     #[wasm_bindgen(method, getter)]
-    pub fn fov(this: &PerspectiveCamera) -> f32;
-    #[wasm_bindgen(method, setter)]
-    pub fn set_fov(this: &PerspectiveCamera, fov: f32);
+    pub fn shadow(this: &SpotLight) -> LightShadow;
+
+    #[derive(Clone)]
+    pub type LightShadow;
     #[wasm_bindgen(method, getter)]
-    pub fn near(this: &PerspectiveCamera) -> f32;
-    #[wasm_bindgen(method, setter)]
-    pub fn set_near(this: &PerspectiveCamera, near: f32);
+    pub fn camera(this: &LightShadow) -> Camera;
+    #[wasm_bindgen(method, getter, js_name = "mapSize")]
+    pub fn map_size(this: &LightShadow) -> Vector2;
     #[wasm_bindgen(method, getter)]
-    pub fn view(this: &PerspectiveCamera) -> js_sys::Object;
+    pub fn bias(this: &LightShadow) -> f32;
     #[wasm_bindgen(method, setter)]
-    pub fn set_view(this: &PerspectiveCamera, view: &js_sys::Object);
+    pub fn set_bias(this: &LightShadow, bias: f32);
+    #[wasm_bindgen(method, getter, js_name = "normalBias")]
+    pub fn normal_bias(this: &LightShadow) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "normalBias")]
+    pub fn set_normal_bias(this: &LightShadow, normal_bias: f32);
     #[wasm_bindgen(method, getter)]
-    pub fn zoom(this: &PerspectiveCamera) -> f32;
+    pub fn radius(this: &LightShadow) -> f32;
     #[wasm_bindgen(method, setter)]
-    pub fn set_zoom(this: &PerspectiveCamera, zoom: f32);
+    pub fn set_radius(this: &LightShadow, radius: f32);
+    #[wasm_bindgen(method, getter, js_name = "blurSamples")]
+    pub fn blur_samples(this: &LightShadow) -> u32;
+    #[wasm_bindgen(method, setter, js_name = "blurSamples")]
+    pub fn set_blur_samples(this: &LightShadow, blur_samples: u32);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &LightShadow);
 
     #[wasm_bindgen(extends = Object3D)]
     pub type Mesh;
@@ -209,6 +2350,20 @@ extern "C" {
     pub type BufferGeometry;
     #[wasm_bindgen(method)]
     pub fn dispose(this: &BufferGeometry);
+    #[wasm_bindgen(method, js_name = "clone")]
+    pub fn clone_geometry(this: &BufferGeometry) -> BufferGeometry;
+    #[wasm_bindgen(method, js_name = "copy")]
+    pub fn copy_geometry(this: &BufferGeometry, source: &BufferGeometry);
+
+    /// An entry of `BufferGeometry.groups`, drawn with a single material.
+    #[derive(Clone)]
+    pub type GeometryGroup;
+    #[wasm_bindgen(method, getter)]
+    pub fn start(this: &GeometryGroup) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn count(this: &GeometryGroup) -> u32;
+    #[wasm_bindgen(method, getter, js_name = "materialIndex")]
+    pub fn material_index(this: &GeometryGroup) -> u32;
 
     #[derive(Clone)]
     #[wasm_bindgen(extends = BufferGeometry)]
@@ -299,6 +2454,10 @@ extern "C" {
 
     #[derive(Clone)]
     pub type Material;
+    #[wasm_bindgen(method, js_name = "clone")]
+    pub fn clone_material(this: &Material) -> Material;
+    #[wasm_bindgen(method, js_name = "copy")]
+    pub fn copy_material(this: &Material, source: &Material);
 
     #[derive(Clone)]
     #[wasm_bindgen(extends = Material)]
@@ -320,16 +2479,118 @@ extern "C" {
     pub fn new(x: f32, y: f32) -> Vector2;
     #[wasm_bindgen(constructor)]
     pub fn default() -> Vector2;
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Vector2) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_x(this: &Vector2, x: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Vector2) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_y(this: &Vector2, y: f32);
+    #[wasm_bindgen(method)]
+    pub fn set(this: &Vector2, x: f32, y: f32) -> Vector2;
 
     pub type Vector3;
     #[wasm_bindgen(constructor)]
     pub fn new(x: f32, y: f32) -> Vector3;
     #[wasm_bindgen(constructor)]
     pub fn default() -> Vector3;
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Vector3) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_x(this: &Vector3, x: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Vector3) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_y(this: &Vector3, y: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn z(this: &Vector3) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_z(this: &Vector3, z: f32);
+    #[wasm_bindgen(method)]
+    pub fn set(this: &Vector3, x: f32, y: f32, z: f32) -> Vector3;
+    #[wasm_bindgen(method, js_name = "toArray")]
+    pub fn to_array(this: &Vector3, array: &mut [f32]);
+
+    pub type Vector4;
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> Vector4;
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Vector4) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Vector4) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn z(this: &Vector4) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn w(this: &Vector4) -> f32;
 
     pub type Euler;
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32, z: f32) -> Euler;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> Euler;
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Euler) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_x(this: &Euler, x: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Euler) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_y(this: &Euler, y: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn z(this: &Euler) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_z(this: &Euler, z: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn order(this: &Euler) -> String;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_order(this: &Euler, order: &str);
+    #[wasm_bindgen(method)]
+    pub fn set(this: &Euler, x: f32, y: f32, z: f32) -> Euler;
+
     pub type Mat4;
 
+    pub type Quaternion;
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> Quaternion;
+    #[wasm_bindgen(method, getter)]
+    pub fn x(this: &Quaternion) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn y(this: &Quaternion) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn z(this: &Quaternion) -> f32;
+    #[wasm_bindgen(method, getter)]
+    pub fn w(this: &Quaternion) -> f32;
+    #[wasm_bindgen(method)]
+    pub fn set(this: &Quaternion, x: f32, y: f32, z: f32, w: f32) -> Quaternion;
+    #[wasm_bindgen(method, js_name = "toArray")]
+    pub fn to_array(this: &Quaternion, array: &mut [f32]);
+
+    pub type Matrix4;
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Matrix4;
+    #[wasm_bindgen(method, js_name = "fromArray")]
+    pub fn from_array(this: &Matrix4, array: &[f32]) -> Matrix4;
+    #[wasm_bindgen(method, js_name = "toArray")]
+    pub fn to_array(this: &Matrix4, array: &mut [f32]);
+    #[wasm_bindgen(method)]
+    pub fn identity(this: &Matrix4) -> Matrix4;
+    #[wasm_bindgen(method)]
+    pub fn invert(this: &Matrix4) -> Matrix4;
+
+    #[derive(Clone)]
+    pub type Color;
+    #[wasm_bindgen(constructor)]
+    pub fn new(hex: u32) -> Color;
+    #[wasm_bindgen(method, js_name = "getHex")]
+    pub fn get_hex(this: &Color) -> u32;
+    #[wasm_bindgen(method, js_name = "setHex")]
+    pub fn set_hex(this: &Color, hex: u32) -> Color;
+
     pub type Plane;
 
     #[derive(Clone)]
@@ -391,6 +2652,8 @@ extern "C" {
     pub fn add_event_listener(this: &EventDispatcher, event: &str, f: &Function);
     #[wasm_bindgen(method, js_name = "removeEventListener")]
     pub fn remove_event_listner(this: &EventDispatcher, event: &str, f: &Function);
+    #[wasm_bindgen(method, js_name = "dispatchEvent")]
+    pub fn dispatch_event(this: &EventDispatcher, event: &Object);
 
     pub type AnimationAction;
     #[wasm_bindgen(constructor)]
@@ -490,7 +2753,55 @@ extern "C" {
     #[wasm_bindgen(method, setter)]
     pub fn set_object(this: &Controls, object: &Object3D);
 
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = EventDispatcher)]
     pub type Texture;
+    #[wasm_bindgen(method, getter)]
+    pub fn uuid(this: &Texture) -> String;
+    #[wasm_bindgen(method, getter = type)]
+    pub fn texture_type_value(this: &Texture) -> u32;
+    #[wasm_bindgen(method, setter = type)]
+    pub fn set_texture_type(this: &Texture, texture_type: TextureDataType);
+    #[wasm_bindgen(method, getter = format)]
+    pub fn format_value(this: &Texture) -> u32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_format(this: &Texture, format: PixelFormat);
+    #[wasm_bindgen(method, setter, js_name = "needsUpdate")]
+    pub fn set_needs_update(this: &Texture, needs_update: bool);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &Texture);
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Texture)]
+    pub type DepthTexture;
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: u32, height: u32) -> DepthTexture;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = EventDispatcher)]
+    pub type WebGLRenderTarget;
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(width: u32, height: u32, options: &Object) -> WebGLRenderTarget;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> WebGLRenderTarget;
+    #[wasm_bindgen(method, getter)]
+    pub fn width(this: &WebGLRenderTarget) -> u32;
+    #[wasm_bindgen(method, getter)]
+    pub fn height(this: &WebGLRenderTarget) -> u32;
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &WebGLRenderTarget, width: u32, height: u32);
+    #[wasm_bindgen(method, getter)]
+    pub fn samples(this: &WebGLRenderTarget) -> u32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_samples(this: &WebGLRenderTarget, samples: u32);
+    #[wasm_bindgen(method, getter)]
+    pub fn texture(this: &WebGLRenderTarget) -> Texture;
+    #[wasm_bindgen(method, getter, js_name = "depthTexture")]
+    pub fn depth_texture(this: &WebGLRenderTarget) -> Option<DepthTexture>;
+    #[wasm_bindgen(method, setter, js_name = "depthTexture")]
+    pub fn set_depth_texture(this: &WebGLRenderTarget, depth_texture: Option<&DepthTexture>);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &WebGLRenderTarget);
 
     pub type LoadingManager;
 
@@ -515,6 +2826,299 @@ extern "C" {
     ) -> Texture;
 }
 
+#[cfg(feature = "webgpu")]
+#[wasm_bindgen(raw_module = "three/webgpu")]
+extern "C" {
+    #[derive(Clone)]
+    pub type WebGPURenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(parameters: &Object) -> WebGPURenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> WebGPURenderer;
+
+    #[wasm_bindgen(method, js_name = "init")]
+    pub fn init_promise(this: &WebGPURenderer) -> js_sys::Promise;
+    #[wasm_bindgen(method, getter)]
+    pub fn initialized(this: &WebGPURenderer) -> bool;
+    #[wasm_bindgen(method)]
+    pub fn render(this: &WebGPURenderer, scene: &Object3D, camera: &Camera);
+    #[wasm_bindgen(method, js_name = "renderAsync")]
+    pub fn render_async_promise(
+        this: &WebGPURenderer,
+        scene: &Object3D,
+        camera: &Camera,
+    ) -> js_sys::Promise;
+    #[wasm_bindgen(method, js_name = "setAnimationLoop")]
+    pub fn set_animation_loop(this: &WebGPURenderer, animate: Option<&Function>);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &WebGPURenderer, width: f32, height: f32, update_style: bool);
+    #[wasm_bindgen(method, js_name = "setPixelRatio")]
+    pub fn set_pixel_ratio(this: &WebGPURenderer, ratio: f32);
+    #[wasm_bindgen(method, getter, js_name = "domElement")]
+    pub fn dom_element(this: &WebGPURenderer) -> HtmlCanvasElement;
+    #[wasm_bindgen(method, js_name = "setClearColor")]
+    pub fn set_clear_color(this: &WebGPURenderer, color: u32);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &WebGPURenderer);
+}
+
+#[cfg(feature = "xr")]
+#[wasm_bindgen(raw_module = "three")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = EventDispatcher)]
+    pub type WebXRManager;
+    #[wasm_bindgen(method, getter)]
+    pub fn enabled(this: &WebXRManager) -> bool;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_enabled(this: &WebXRManager, enabled: bool);
+    #[wasm_bindgen(method, getter, js_name = "isPresenting")]
+    pub fn is_presenting(this: &WebXRManager) -> bool;
+    #[wasm_bindgen(method, js_name = "setReferenceSpaceType")]
+    pub fn set_reference_space_type(this: &WebXRManager, space: XrReferenceSpaceType);
+    #[wasm_bindgen(method, js_name = "getController")]
+    pub fn get_controller(this: &WebXRManager, index: u32) -> Group;
+    #[wasm_bindgen(method, js_name = "getControllerGrip")]
+    pub fn get_controller_grip(this: &WebXRManager, index: u32) -> Group;
+    #[wasm_bindgen(method, js_name = "getHand")]
+    pub fn get_hand(this: &WebXRManager, index: u32) -> Group;
+    #[wasm_bindgen(method, js_name = "getCamera")]
+    pub fn get_camera(this: &WebXRManager) -> PerspectiveCamera;
+    #[wasm_bindgen(method, js_name = "getSession")]
+    pub fn get_session(this: &WebXRManager) -> Option<Object>;
+}
+
+#[cfg(feature = "xr")]
+#[wasm_bindgen(raw_module = "three/addons/webxr/VRButton.js")]
+extern "C" {
+    pub type VRButton;
+    #[wasm_bindgen(static_method_of = VRButton, js_name = "createButton")]
+    pub fn create_button(renderer: &WebGLRenderer) -> HtmlElement;
+    #[wasm_bindgen(static_method_of = VRButton, js_name = "createButton")]
+    pub fn create_button_with_options(
+        renderer: &WebGLRenderer,
+        session_init: &Object,
+    ) -> HtmlElement;
+}
+
+#[cfg(feature = "xr")]
+#[wasm_bindgen(raw_module = "three/addons/webxr/ARButton.js")]
+extern "C" {
+    pub type ARButton;
+    #[wasm_bindgen(static_method_of = ARButton, js_name = "createButton")]
+    pub fn create_button(renderer: &WebGLRenderer) -> HtmlElement;
+    #[wasm_bindgen(static_method_of = ARButton, js_name = "createButton")]
+    pub fn create_button_with_options(
+        renderer: &WebGLRenderer,
+        session_init: &Object,
+    ) -> HtmlElement;
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/EffectComposer.js")]
+extern "C" {
+    #[derive(Clone)]
+    pub type EffectComposer;
+    #[wasm_bindgen(constructor)]
+    pub fn new(renderer: &WebGLRenderer) -> EffectComposer;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_render_target(
+        renderer: &WebGLRenderer,
+        render_target: &WebGLRenderTarget,
+    ) -> EffectComposer;
+    #[wasm_bindgen(method, js_name = "addPass")]
+    pub fn add_pass(this: &EffectComposer, pass: &Pass);
+    #[wasm_bindgen(method, js_name = "insertPass")]
+    pub fn insert_pass(this: &EffectComposer, pass: &Pass, index: u32);
+    #[wasm_bindgen(method, js_name = "removePass")]
+    pub fn remove_pass(this: &EffectComposer, pass: &Pass);
+    #[wasm_bindgen(method)]
+    pub fn render(this: &EffectComposer, delta_time: Option<f32>);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &EffectComposer, width: f32, height: f32);
+    #[wasm_bindgen(method, js_name = "setPixelRatio")]
+    pub fn set_pixel_ratio(this: &EffectComposer, ratio: f32);
+    #[wasm_bindgen(method)]
+    pub fn reset(this: &EffectComposer, render_target: Option<&WebGLRenderTarget>);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &EffectComposer);
+    #[wasm_bindgen(method, getter, js_name = "renderToScreen")]
+    pub fn render_to_screen(this: &EffectComposer) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "renderToScreen")]
+    pub fn set_render_to_screen(this: &EffectComposer, render_to_screen: bool);
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/Pass.js")]
+extern "C" {
+    #[derive(Clone)]
+    pub type Pass;
+    #[wasm_bindgen(method, getter)]
+    pub fn enabled(this: &Pass) -> bool;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_enabled(this: &Pass, enabled: bool);
+    #[wasm_bindgen(method, getter, js_name = "needsSwap")]
+    pub fn needs_swap(this: &Pass) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "needsSwap")]
+    pub fn set_needs_swap(this: &Pass, needs_swap: bool);
+    #[wasm_bindgen(method, getter)]
+    pub fn clear(this: &Pass) -> bool;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_clear(this: &Pass, clear: bool);
+    #[wasm_bindgen(method, getter, js_name = "renderToScreen")]
+    pub fn render_to_screen(this: &Pass) -> bool;
+    #[wasm_bindgen(method, setter, js_name = "renderToScreen")]
+    pub fn set_render_to_screen(this: &Pass, render_to_screen: bool);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &Pass, width: f32, height: f32);
+    #[wasm_bindgen(method)]
+    pub fn dispose(this: &Pass);
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/RenderPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type RenderPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(scene: &Object3D, camera: &Camera) -> RenderPass;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_scene(this: &RenderPass, scene: &Object3D);
+    #[wasm_bindgen(method, setter)]
+    pub fn set_camera(this: &RenderPass, camera: &Camera);
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/ShaderPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type ShaderPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(shader: &Object) -> ShaderPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_texture_id(shader: &Object, texture_id: &str) -> ShaderPass;
+    #[wasm_bindgen(method, getter)]
+    pub fn uniforms(this: &ShaderPass) -> Object;
+    #[wasm_bindgen(method, getter)]
+    pub fn material(this: &ShaderPass) -> Material;
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/OutputPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type OutputPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> OutputPass;
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/UnrealBloomPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type UnrealBloomPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(resolution: &Vector2, strength: f32, radius: f32, threshold: f32)
+    -> UnrealBloomPass;
+    #[wasm_bindgen(method, getter)]
+    pub fn strength(this: &UnrealBloomPass) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_strength(this: &UnrealBloomPass, strength: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn radius(this: &UnrealBloomPass) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_radius(this: &UnrealBloomPass, radius: f32);
+    #[wasm_bindgen(method, getter)]
+    pub fn threshold(this: &UnrealBloomPass) -> f32;
+    #[wasm_bindgen(method, setter)]
+    pub fn set_threshold(this: &UnrealBloomPass, threshold: f32);
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/OutlinePass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type OutlinePass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(resolution: &Vector2, scene: &Object3D, camera: &Camera) -> OutlinePass;
+    #[wasm_bindgen(method, getter, js_name = "selectedObjects")]
+    pub fn selected_objects_array(this: &OutlinePass) -> Array;
+    #[wasm_bindgen(method, setter, js_name = "selectedObjects")]
+    pub fn set_selected_objects_array(this: &OutlinePass, objects: &Array);
+    #[wasm_bindgen(method, getter, js_name = "edgeStrength")]
+    pub fn edge_strength(this: &OutlinePass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "edgeStrength")]
+    pub fn set_edge_strength(this: &OutlinePass, edge_strength: f32);
+    #[wasm_bindgen(method, getter, js_name = "edgeGlow")]
+    pub fn edge_glow(this: &OutlinePass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "edgeGlow")]
+    pub fn set_edge_glow(this: &OutlinePass, edge_glow: f32);
+    #[wasm_bindgen(method, getter, js_name = "edgeThickness")]
+    pub fn edge_thickness(this: &OutlinePass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "edgeThickness")]
+    pub fn set_edge_thickness(this: &OutlinePass, edge_thickness: f32);
+    #[wasm_bindgen(method, getter, js_name = "pulsePeriod")]
+    pub fn pulse_period(this: &OutlinePass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "pulsePeriod")]
+    pub fn set_pulse_period(this: &OutlinePass, pulse_period: f32);
+    #[wasm_bindgen(method, getter, js_name = "visibleEdgeColor")]
+    pub fn visible_edge_color(this: &OutlinePass) -> Color;
+    #[wasm_bindgen(method, getter, js_name = "hiddenEdgeColor")]
+    pub fn hidden_edge_color(this: &OutlinePass) -> Color;
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/SMAAPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type SMAAPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: f32, height: f32) -> SMAAPass;
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/postprocessing/SSAOPass.js")]
+extern "C" {
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Pass)]
+    pub type SSAOPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new(scene: &Object3D, camera: &Camera, width: f32, height: f32) -> SSAOPass;
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_kernel_size(
+        scene: &Object3D,
+        camera: &Camera,
+        width: f32,
+        height: f32,
+        kernel_size: u32,
+    ) -> SSAOPass;
+    #[wasm_bindgen(method, getter, js_name = "kernelRadius")]
+    pub fn kernel_radius(this: &SSAOPass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "kernelRadius")]
+    pub fn set_kernel_radius(this: &SSAOPass, kernel_radius: f32);
+    #[wasm_bindgen(method, getter, js_name = "minDistance")]
+    pub fn min_distance(this: &SSAOPass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "minDistance")]
+    pub fn set_min_distance(this: &SSAOPass, min_distance: f32);
+    #[wasm_bindgen(method, getter, js_name = "maxDistance")]
+    pub fn max_distance(this: &SSAOPass) -> f32;
+    #[wasm_bindgen(method, setter, js_name = "maxDistance")]
+    pub fn set_max_distance(this: &SSAOPass, max_distance: f32);
+}
+
+#[cfg(feature = "postprocessing")]
+#[wasm_bindgen(raw_module = "three/addons/shaders/FXAAShader.js")]
+extern "C" {
+    #[wasm_bindgen(thread_local_v2, js_name = "FXAAShader")]
+    pub static FXAA_SHADER: Object;
+}
+
 #[wasm_bindgen(raw_module = "three/addons/controls/DragControls.js")]
 extern "C" {
     #[derive(Clone)]
@@ -525,12 +3129,20 @@ extern "C" {
     -> DragControls;
 }
 
-#[wasm_bindgen(raw_module = "three/addons/renderers/Css3DRenderer.js")]
+#[wasm_bindgen(raw_module = "three/addons/renderers/CSS3DRenderer.js")]
 extern "C" {
     #[derive(Clone)]
     pub type CSS3DRenderer;
     #[wasm_bindgen(constructor)]
     pub fn default() -> CSS3DRenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(parameters: &Object) -> CSS3DRenderer;
+    #[wasm_bindgen(method)]
+    pub fn render(this: &CSS3DRenderer, scene: &Object3D, camera: &Camera);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &CSS3DRenderer, width: f32, height: f32);
+    #[wasm_bindgen(method, getter, js_name = "domElement")]
+    pub fn dom_element(this: &CSS3DRenderer) -> HtmlElement;
 
     #[derive(Clone)]
     #[wasm_bindgen(extends = Object3D)]
@@ -540,17 +3152,72 @@ extern "C" {
     #[wasm_bindgen(constructor)]
     pub fn default() -> CSS3DObject;
 
-    #[wasm_bindgen(method, getter)]
+    #[wasm_bindgen(method, getter = element)]
     pub fn get_element(this: &CSS3DObject) -> HtmlElement;
     #[wasm_bindgen(method, setter)]
     pub fn set_element(this: &CSS3DObject, element: &HtmlElement) -> HtmlElement;
 
     #[derive(Clone)]
+    #[wasm_bindgen(extends = CSS3DObject, extends = Object3D)]
     pub type CSS3DSprite;
     #[wasm_bindgen(constructor)]
     pub fn new(element: HtmlElement) -> CSS3DSprite;
 }
 
+#[wasm_bindgen(raw_module = "three/addons/renderers/CSS2DRenderer.js")]
+extern "C" {
+    #[derive(Clone)]
+    pub type CSS2DRenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> CSS2DRenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(parameters: &Object) -> CSS2DRenderer;
+    #[wasm_bindgen(method)]
+    pub fn render(this: &CSS2DRenderer, scene: &Object3D, camera: &Camera);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &CSS2DRenderer, width: f32, height: f32);
+    #[wasm_bindgen(method, getter, js_name = "domElement")]
+    pub fn dom_element(this: &CSS2DRenderer) -> HtmlElement;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Object3D)]
+    pub type CSS2DObject;
+    #[wasm_bindgen(constructor)]
+    pub fn new(element: HtmlElement) -> CSS2DObject;
+    #[wasm_bindgen(constructor)]
+    pub fn default() -> CSS2DObject;
+    #[wasm_bindgen(method, getter)]
+    pub fn element(this: &CSS2DObject) -> HtmlElement;
+    #[wasm_bindgen(method, getter)]
+    pub fn center(this: &CSS2DObject) -> Vector2;
+}
+
+#[wasm_bindgen(raw_module = "three/addons/renderers/SVGRenderer.js")]
+extern "C" {
+    #[derive(Clone)]
+    pub type SVGRenderer;
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> SVGRenderer;
+    #[wasm_bindgen(method)]
+    pub fn render(this: &SVGRenderer, scene: &Object3D, camera: &Camera);
+    #[wasm_bindgen(method, js_name = "setSize")]
+    pub fn set_size(this: &SVGRenderer, width: f32, height: f32);
+    #[wasm_bindgen(method, js_name = "setQuality")]
+    pub fn set_quality(this: &SVGRenderer, quality: SvgQuality);
+    #[wasm_bindgen(method, js_name = "setClearColor")]
+    pub fn set_clear_color(this: &SVGRenderer, color: u32);
+    #[wasm_bindgen(method, getter, js_name = "domElement")]
+    pub fn dom_element(this: &SVGRenderer) -> SvgsvgElement;
+
+    #[derive(Clone)]
+    #[wasm_bindgen(extends = Object3D)]
+    pub type SVGObject;
+    #[wasm_bindgen(constructor)]
+    pub fn new(node: &SvgElement) -> SVGObject;
+    #[wasm_bindgen(method, getter)]
+    pub fn node(this: &SVGObject) -> SvgElement;
+}
+
 #[wasm_bindgen(module = "/src/utils.js")]
 extern "C" {
     pub fn set_user_data(object: &Object3D, key: &str, v: &JsValue);
@@ -564,6 +3231,40 @@ extern "C" {
 
     pub fn batch_add_position(object: &Object3D, x: f32, y: f32, z: f32);
 
+    pub fn copy_position(object: &Object3D, out: &mut [f32]);
+    pub fn copy_rotation(object: &Object3D, out: &mut [f32]);
+    pub fn copy_quaternion(object: &Object3D, out: &mut [f32]);
+    pub fn copy_scale(object: &Object3D, out: &mut [f32]);
+    pub fn copy_up(object: &Object3D, out: &mut [f32]);
+    pub fn copy_matrix(object: &Object3D, out: &mut [f32]);
+    pub fn copy_matrix_world(object: &Object3D, out: &mut [f32]);
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn compose_object_matrix(
+        object: &Object3D,
+        px: f32,
+        py: f32,
+        pz: f32,
+        qx: f32,
+        qy: f32,
+        qz: f32,
+        qw: f32,
+        sx: f32,
+        sy: f32,
+        sz: f32,
+    );
+    pub fn set_object_matrix(object: &Object3D, elements: &[f32]);
+
+    pub fn copy_world_position(object: &Object3D, out: &mut [f32]);
+    pub fn copy_world_quaternion(object: &Object3D, out: &mut [f32]);
+    pub fn copy_world_scale(object: &Object3D, out: &mut [f32]);
+    pub fn copy_world_direction(object: &Object3D, out: &mut [f32]);
+    pub fn local_to_world_in_place(object: &Object3D, point: &mut [f32]);
+    pub fn world_to_local_in_place(object: &Object3D, point: &mut [f32]);
+    pub fn rotate_object_on_axis(object: &Object3D, x: f32, y: f32, z: f32, angle: f32);
+    pub fn translate_object_on_axis(object: &Object3D, x: f32, y: f32, z: f32, distance: f32);
+    pub fn apply_matrix_elements(object: &Object3D, elements: &[f32]);
+
     pub fn batch_update_transform(
         object: &Object3D,
         x: f32,
@@ -587,6 +3288,10 @@ extern "C" {
 
     pub fn reparent_object(object: &Object3D, parent: &Object3D);
 
+    pub fn reset_render_hook(object: &Object3D, property: &str, hook: &Function);
+
+    pub fn deep_clone_object(object: &Object3D) -> Object3D;
+
     pub fn set_raycaster_from_camera_and_ndc(raycater: &Raycaster, camera: &Camera, x: f32, y: f32);
 
     pub fn lerp_object_to(object: &Object3D, tx: f32, ty: f32, tz: f32, alpha: f32);
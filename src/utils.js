@@ -1,10 +1,22 @@
-import { Vector2, Vector3, Plane, Path, Shape } from "three";
+import {
+  Vector2,
+  Vector3,
+  Quaternion,
+  Matrix4,
+  Plane,
+  Path,
+  Shape,
+} from "three";
 
 const screenSize = new Vector2();
 const screenPosition = new Vector3();
 const xy_plane = new Plane(new Vector3(0, 0, 1), 0);
 const intersection_cache = new Vector3();
 const coords = new Vector2();
+const scratchVector = new Vector3();
+const scratchQuaternion = new Quaternion();
+const scratchMatrix = new Matrix4();
+const scratchScale = new Vector3();
 
 export function set_user_data(object, key, value) {
   object.userData[key] = value;
@@ -20,6 +32,98 @@ export function batch_add_position(object, x, y, z) {
   object.position.z += z;
 }
 
+export function copy_position(object, out) {
+  object.position.toArray(out);
+}
+
+export function copy_rotation(object, out) {
+  out[0] = object.rotation.x;
+  out[1] = object.rotation.y;
+  out[2] = object.rotation.z;
+}
+
+export function copy_quaternion(object, out) {
+  object.quaternion.toArray(out);
+}
+
+export function copy_scale(object, out) {
+  object.scale.toArray(out);
+}
+
+export function copy_up(object, out) {
+  object.up.toArray(out);
+}
+
+export function copy_matrix(object, out) {
+  object.matrix.toArray(out);
+}
+
+export function copy_matrix_world(object, out) {
+  object.matrixWorld.toArray(out);
+}
+
+export function compose_object_matrix(
+  object,
+  px,
+  py,
+  pz,
+  qx,
+  qy,
+  qz,
+  qw,
+  sx,
+  sy,
+  sz
+) {
+  object.matrix.compose(
+    scratchVector.set(px, py, pz),
+    scratchQuaternion.set(qx, qy, qz, qw),
+    scratchScale.set(sx, sy, sz)
+  );
+  object.matrixWorldNeedsUpdate = true;
+}
+
+export function set_object_matrix(object, elements) {
+  object.matrix.fromArray(elements);
+  object.matrixWorldNeedsUpdate = true;
+}
+
+export function copy_world_position(object, out) {
+  object.getWorldPosition(scratchVector).toArray(out);
+}
+
+export function copy_world_quaternion(object, out) {
+  object.getWorldQuaternion(scratchQuaternion).toArray(out);
+}
+
+export function copy_world_scale(object, out) {
+  object.getWorldScale(scratchVector).toArray(out);
+}
+
+export function copy_world_direction(object, out) {
+  object.getWorldDirection(scratchVector).toArray(out);
+}
+
+export function local_to_world_in_place(object, point) {
+  object.localToWorld(scratchVector.fromArray(point)).toArray(point);
+}
+
+export function world_to_local_in_place(object, point) {
+  object.worldToLocal(scratchVector.fromArray(point)).toArray(point);
+}
+
+export function rotate_object_on_axis(object, x, y, z, angle) {
+  object.rotateOnAxis(scratchVector.set(x, y, z), angle);
+}
+
+export function translate_object_on_axis(object, x, y, z, distance) {
+  object.translateOnAxis(scratchVector.set(x, y, z), distance);
+}
+
+export function apply_matrix_elements(object, elements) {
+  object.applyMatrix4(scratchMatrix.fromArray(elements));
+}
+
 export function batch_update_quaternion(object, x, y, z, w) {
   object.quaternion.set(x, y, z, w);
 }
@@ -70,6 +174,34 @@ export function reparent_object(object, newParent) {
   newParent.add(object);
 }
 
+export function reset_render_hook(object, property, hook) {
+  if (object[property] === hook) {
+    delete object[property];
+  }
+}
+
+export function deep_clone_object(object) {
+  const clones = new Map();
+  const cloneResource = (resource) => {
+    if (!clones.has(resource)) {
+      clones.set(resource, resource.clone());
+    }
+    return clones.get(resource);
+  };
+  const clone = object.clone(true);
+  clone.traverse((node) => {
+    if (node.geometry) {
+      node.geometry = cloneResource(node.geometry);
+    }
+    if (Array.isArray(node.material)) {
+      node.material = node.material.map(cloneResource);
+    } else if (node.material) {
+      node.material = cloneResource(node.material);
+    }
+  });
+  return clone;
+}
+
 export function set_raycaster_from_camera_and_ndc(raycaster, camera, x, y) {
   coords.x = x;
   coords.y = y;
@@ -0,0 +1,75 @@
+//! Runs with `wasm-pack test --node`. The renderer is a stub that records the
+//! callback passed to `setAnimationLoop`, so no WebGL context is needed.
+
+use js_sys::{Object, Reflect};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use wasm_bindgen_three::*;
+
+struct StubRenderer {
+    renderer: WebGLRenderer,
+    installed: Rc<RefCell<JsValue>>,
+    _set_animation_loop: Closure<dyn FnMut(JsValue)>,
+}
+
+impl StubRenderer {
+    fn new() -> Self {
+        let installed = Rc::new(RefCell::new(JsValue::NULL));
+        let set_animation_loop = Closure::<dyn FnMut(JsValue)>::new({
+            let installed = installed.clone();
+            move |animate: JsValue| *installed.borrow_mut() = animate
+        });
+        let renderer = Object::new();
+        Reflect::set(
+            &renderer,
+            &"setAnimationLoop".into(),
+            set_animation_loop.as_ref(),
+        )
+        .unwrap();
+        Self {
+            renderer: renderer.unchecked_into(),
+            installed,
+            _set_animation_loop: set_animation_loop,
+        }
+    }
+    fn is_running(&self) -> bool {
+        !self.installed.borrow().is_null()
+    }
+}
+
+struct App {
+    frame: AnimationLoop,
+}
+
+#[wasm_bindgen_test]
+fn drop_clears_the_loop() {
+    let stub = StubRenderer::new();
+    let animation_loop = stub.renderer.animation_loop(|_, _| {});
+    assert!(stub.is_running());
+    drop(animation_loop);
+    assert!(!stub.is_running());
+}
+
+#[wasm_bindgen_test]
+fn replacing_a_loop_keeps_the_new_one_running() {
+    let stub = StubRenderer::new();
+    let mut app = App {
+        frame: stub.renderer.animation_loop(|_, _| {}),
+    };
+    app.frame = stub.renderer.animation_loop(|_, _| {});
+    assert!(stub.is_running());
+
+    drop(app);
+    assert!(!stub.is_running());
+}
+
+#[wasm_bindgen_test]
+fn pausing_a_replaced_loop_keeps_the_new_one_running() {
+    let stub = StubRenderer::new();
+    let mut stale = stub.renderer.animation_loop(|_, _| {});
+    let _current = stub.renderer.animation_loop(|_, _| {});
+    stale.pause();
+    assert!(stub.is_running());
+}
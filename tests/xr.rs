@@ -0,0 +1,84 @@
+//! Runs with `wasm-pack test --node --features xr` and `three` installed in
+//! `node_modules`. WebGL is not needed: the `WebXRManager` under test is
+//! built on a stub context, and session events are dispatched the way
+//! three.js does once a session starts or ends.
+#![cfg(feature = "xr")]
+
+use js_sys::{Object, Reflect};
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use wasm_bindgen_three::*;
+
+#[wasm_bindgen(raw_module = "three")]
+extern "C" {
+    #[wasm_bindgen(js_name = "WebXRManager")]
+    type XrManagerClass;
+    #[wasm_bindgen(constructor, js_class = "WebXRManager")]
+    fn new(renderer: &JsValue, gl: &JsValue) -> XrManagerClass;
+}
+
+/// A renderer exposing only `xr`, which is all `XrSessionListener` touches.
+fn stub_renderer() -> WebGLRenderer {
+    let gl = Object::new();
+    let attributes = Closure::<dyn Fn() -> JsValue>::new(|| {
+        let attributes = Object::new();
+        Reflect::set(&attributes, &"xrCompatible".into(), &true.into()).unwrap();
+        attributes.into()
+    });
+    Reflect::set(
+        &gl,
+        &"getContextAttributes".into(),
+        &attributes.into_js_value(),
+    )
+    .unwrap();
+    let renderer = Object::new();
+    let xr = XrManagerClass::new(&renderer, &gl);
+    Reflect::set(&renderer, &"xr".into(), &xr).unwrap();
+    renderer.unchecked_into()
+}
+
+fn dispatch(xr: &WebXRManager, kind: &str) {
+    let event = Object::new();
+    Reflect::set(&event, &"type".into(), &kind.into()).unwrap();
+    xr.dispatch_event(&event);
+}
+
+#[wasm_bindgen_test]
+fn controller_is_an_object3d() {
+    let renderer = stub_renderer();
+    let controller = renderer.xr().get_controller(0);
+    let scene = Scene::new();
+    scene.add(vec![controller.clone().into()]);
+    assert_eq!(scene.children().length(), 1);
+    assert!(controller.parent().is_some());
+}
+
+#[wasm_bindgen_test]
+fn session_listener_fires_until_dropped() {
+    let renderer = stub_renderer();
+    let xr = renderer.xr();
+    let started = Rc::new(Cell::new(0));
+    let ended = Rc::new(Cell::new(0));
+    let listener = XrSessionListener::new(
+        &renderer,
+        {
+            let started = started.clone();
+            move || started.set(started.get() + 1)
+        },
+        {
+            let ended = ended.clone();
+            move || ended.set(ended.get() + 1)
+        },
+    );
+
+    dispatch(&xr, "sessionstart");
+    dispatch(&xr, "sessionend");
+    assert_eq!((started.get(), ended.get()), (1, 1));
+
+    drop(listener);
+    dispatch(&xr, "sessionstart");
+    dispatch(&xr, "sessionend");
+    assert_eq!((started.get(), ended.get()), (1, 1));
+}